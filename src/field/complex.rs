@@ -1,5 +1,5 @@
 use std::{ops::{Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign, Neg}, fmt};
-use super::{Real, Field};
+use super::{Real, Field, ConjugateField};
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 /// A type representing a complex number.
@@ -178,4 +178,22 @@ impl Field for Complex {
     fn zero() -> Self {
         Complex::from(0.0)
     }
+
+    fn magnitude(self) -> Option<Real> {
+        Some(self.mag_sq().sqrt())
+    }
+}
+
+impl ConjugateField for Complex {
+    fn conj(self) -> Self {
+        self.conjugate()
+    }
+
+    fn real_part(self) -> Real {
+        self.re
+    }
+
+    fn modulus(self) -> Real {
+        self.mag_sq().sqrt()
+    }
 }
\ No newline at end of file
@@ -7,7 +7,7 @@ pub enum IntMod2 {
 use super::{Real, Complex, Int, Rational};
 use std::ops::{Add, Sub, Mul, Div, AddAssign, MulAssign, DivAssign, SubAssign, Neg};
 use std::{fmt};
-use super::Field;
+use super::{Field, FiniteField};
 
 impl Into<Int> for IntMod2 {
     fn into(self) -> Int {
@@ -172,4 +172,10 @@ impl Field for IntMod2 {
     fn zero() -> Self {
         Self::Zero
     }
-}
\ No newline at end of file
+}
+
+impl FiniteField for IntMod2 {
+    fn elements() -> Vec<Self> {
+        vec![Self::Zero, Self::One]
+    }
+}
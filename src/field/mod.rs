@@ -5,6 +5,7 @@ pub use complex::Complex;
 pub mod rational;
 pub use rational::{Int, Rational};
 mod int_mod_2;
+pub use int_mod_2::IntMod2;
 
 use std::ops::{Add, Sub, Mul, Div, AddAssign, MulAssign, DivAssign, SubAssign, Neg};
 use std::fmt::{Debug, Display};
@@ -43,4 +44,35 @@ pub trait Field: Sized
     fn add_inverse(x: Self) -> Self {
         Self::zero() - x
     }
-}
\ No newline at end of file
+
+    /// Returns the size |x| of an element of an inexact field such as the floating point numbers,
+    /// where elimination should pivot on the largest entry available and treat tiny entries as zero.
+    /// Exact fields return `None`, and any nonzero entry can be used as a pivot.
+    fn magnitude(self) -> Option<Real> {
+        None
+    }
+}
+
+/// A trait for fields equipped with a conjugation and an absolute value, which are the scalars
+/// of inner product spaces. Conjugation must be the identity on real numbers.
+pub trait ConjugateField: Field {
+    /// Returns the complex conjugate of x.
+    fn conj(self) -> Self;
+
+    /// Returns the real part of x.
+    fn real_part(self) -> Real;
+
+    /// Returns the absolute value |x|.
+    fn modulus(self) -> Real;
+}
+
+/// A trait for fields with finitely many elements.
+pub trait FiniteField: Field {
+    /// Returns all elements of the field, starting with zero and one.
+    fn elements() -> Vec<Self>;
+
+    /// Returns the number of elements q of the field.
+    fn order() -> usize {
+        Self::elements().len()
+    }
+}
@@ -5,7 +5,7 @@ use super::{Real, Complex};
 use std::ops::{Add, Sub, Mul, Div, AddAssign, MulAssign, DivAssign, SubAssign, Neg};
 use std::{fmt, cmp};
 
-use super::Field;
+use super::{Field, ConjugateField};
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct Rational {
@@ -98,7 +98,7 @@ impl Mul for Rational {
     type Output = Self;
     fn mul(mut self, rhs: Self) -> Self {
         self.num = self.num * rhs.num;
-        self.denom *= self.denom * rhs.denom;
+        self.denom = self.denom * rhs.denom;
         self.simplify();
         self
     }
@@ -213,4 +213,33 @@ impl Field for Rational {
     fn zero() -> Self {
         Self::from(0)
     }
-}
\ No newline at end of file
+}
+
+impl ConjugateField for Rational {
+    fn conj(self) -> Self {
+        self
+    }
+
+    fn real_part(self) -> Real {
+        self.into()
+    }
+
+    fn modulus(self) -> Real {
+        <Self as Into<Real>>::into(self).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rational;
+
+    #[test]
+    fn rational_mul() {
+        let half = Rational::from(1) / Rational::from(2);
+        let two_thirds = Rational::from(2) / Rational::from(3);
+        assert_eq!(half * two_thirds, Rational::from(1) / Rational::from(3));
+        let mut x = Rational::from(3) / Rational::from(4);
+        x *= Rational::from(4) / Rational::from(5);
+        assert_eq!(x, Rational::from(3) / Rational::from(5));
+    }
+}
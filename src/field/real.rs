@@ -1,4 +1,4 @@
-use super::Field;
+use super::{Field, ConjugateField};
 
 /// A type representing a real number.
 pub type Real = f64;
@@ -11,4 +11,22 @@ impl Field for Real {
     fn zero() -> Self {
         0.0
     }
+
+    fn magnitude(self) -> Option<Real> {
+        Some(self.abs())
+    }
+}
+
+impl ConjugateField for Real {
+    fn conj(self) -> Self {
+        self
+    }
+
+    fn real_part(self) -> Real {
+        self
+    }
+
+    fn modulus(self) -> Real {
+        self.abs()
+    }
 }
\ No newline at end of file
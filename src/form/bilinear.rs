@@ -0,0 +1,260 @@
+use crate::field::{Field, Real};
+use crate::vector_space::{Matrix, Tuple, VectorSpace, FiniteDimensional};
+
+/// Entries of an inexact field smaller than this, relative to the largest entry involved, are treated
+/// as zero when choosing pivots, testing symmetry and counting the signs of diagonal entries.
+const TOLERANCE: Real = 1e-10;
+
+/// A type representing a bilinear form H: F^N × F^N -> F.
+/// The form is stored as its matrix ψ_β(H) relative to the standard ordered basis β,
+/// whose entries are A_ij = H(e_i, e_j), so that H(x, y) = xᵗAy.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BilinearForm<F: Field, const N: usize>(Matrix<F, N, N>);
+
+impl<F: Field, const N: usize> BilinearForm<F, N> {
+    /// Creates the bilinear form H(x, y) = xᵗAy.
+    pub fn new(matrix: Matrix<F, N, N>) -> Self {
+        Self(matrix)
+    }
+
+    /// Creates the bilinear form given by the closure, which the caller is trusted to have made bilinear.
+    pub fn from_fn(h: impl Fn(&Tuple<F, N>, &Tuple<F, N>) -> F) -> Self {
+        let basis = Tuple::<F, N>::standard_basis();
+        let mut matrix = <Matrix<F, N, N> as VectorSpace<F>>::zero();
+        for i in 0..N {
+            for j in 0..N {
+                matrix[(i, j)] = h(&basis[i], &basis[j]);
+            }
+        }
+        Self(matrix)
+    }
+
+    /// Returns the matrix of the form relative to the standard ordered basis.
+    pub fn matrix(&self) -> &Matrix<F, N, N> {
+        &self.0
+    }
+
+    /// Evaluates H(x, y) = xᵗAy.
+    pub fn eval(&self, x: &Tuple<F, N>, y: &Tuple<F, N>) -> F {
+        let mut accum = F::zero();
+        for i in 0..N {
+            for j in 0..N {
+                accum += x[i] * self.0[(i, j)] * y[j];
+            }
+        }
+        accum
+    }
+
+    /// Returns the matrix ψ_γ(H) relative to the ordered basis γ, whose entries are H(v_i, v_j).
+    pub fn matrix_relative_to(&self, gamma: &[Tuple<F, N>; N]) -> Matrix<F, N, N> {
+        let mut matrix = <Matrix<F, N, N> as VectorSpace<F>>::zero();
+        for i in 0..N {
+            for j in 0..N {
+                matrix[(i, j)] = self.eval(&gamma[i], &gamma[j]);
+            }
+        }
+        matrix
+    }
+
+    /// Returns the form whose matrix is QᵗAQ. If Q changes γ coordinates into standard coordinates,
+    /// this has the same values as H but its standard matrix is ψ_γ(H) (6.33).
+    pub fn change_basis(&self, q: &Matrix<F, N, N>) -> Self {
+        Self(q.transpose() * self.0 * q)
+    }
+
+    /// Returns whether H(x, y) = H(y, x) for all x, y, which is the case exactly when A = Aᵗ.
+    pub fn is_symmetric(&self) -> bool {
+        self.0 == self.0.transpose()
+    }
+
+    /// Returns the rank of the form, which is the rank of its matrix relative to any basis.
+    pub fn rank(&self) -> usize {
+        self.0.rank()
+    }
+
+    /// Returns whether H(x, x) = 0 for all x, which is the case exactly when A has zero diagonal
+    /// and A = -Aᵗ.
+    pub fn is_alternating(&self) -> bool {
+        (0..N).all(|i| self.0[(i, i)] == F::zero()) && self.0 == -self.0.transpose()
+    }
+
+    /// Diagonalizes a symmetric form by congruence, returning a diagonal matrix D and an invertible
+    /// matrix Q with QᵗAQ = D (6.35). The same elementary operations are applied to the rows and
+    /// columns of A, with the column operations also recorded in Q.
+    /// Returns `None` if the form is not symmetric or the field has characteristic two.
+    /// Over inexact fields, entries within a relative tolerance of zero are treated as zero,
+    /// so that rounding residue is never used as a pivot.
+    pub fn diagonalize(&self) -> Option<(Matrix<F, N, N>, Matrix<F, N, N>)> {
+        if !self.is_symmetric() || F::one() + F::one() == F::zero() {
+            return None;
+        }
+        let mut a = self.0;
+        let mut q = <Matrix<F, N, N> as VectorSpace<F>>::zero();
+        for i in 0..N {
+            q[(i, i)] = F::one();
+        }
+        let tolerance = a.iter().filter_map(|x| x.magnitude()).fold(0.0, Real::max) * TOLERANCE;
+        let is_zero = |x: F| match x.magnitude() {
+            Some(size) => size <= tolerance,
+            None => x == F::zero(),
+        };
+        for k in 0..N {
+            if is_zero(a[(k, k)]) {
+                // Bring a nonzero entry onto the diagonal, either by swapping in a later nonzero diagonal
+                // entry, or by adding a later index j with A_kj nonzero, making A_kk = 2A_kj.
+                if let Some(j) = (k+1..N).find(|&j| !is_zero(a[(j, j)])) {
+                    congruent_swap(&mut a, &mut q, k, j);
+                } else if let Some(j) = (k+1..N).find(|&j| !is_zero(a[(k, j)])) {
+                    congruent_add(&mut a, &mut q, k, j, F::one());
+                } else {
+                    // The rest of row and column k is zero up to rounding.
+                    for j in k..N {
+                        a[(k, j)] = F::zero();
+                        a[(j, k)] = F::zero();
+                    }
+                    continue;
+                }
+            }
+            for i in k+1..N {
+                let c = -a[(i, k)] / a[(k, k)];
+                congruent_add(&mut a, &mut q, i, k, c);
+            }
+        }
+        Some((a, q))
+    }
+}
+
+/// Swaps rows i and j and columns i and j of A, and columns i and j of Q.
+fn congruent_swap<F: Field, const N: usize>(a: &mut Matrix<F, N, N>, q: &mut Matrix<F, N, N>, i: usize, j: usize) {
+    for k in 0..N {
+        let tmp = a[(i, k)];
+        a[(i, k)] = a[(j, k)];
+        a[(j, k)] = tmp;
+    }
+    for m in [&mut *a, &mut *q] {
+        for k in 0..N {
+            let tmp = m[(k, i)];
+            m[(k, i)] = m[(k, j)];
+            m[(k, j)] = tmp;
+        }
+    }
+}
+
+/// Adds c times row j to row i and c times column j to column i of A, and the same column operation to Q.
+fn congruent_add<F: Field, const N: usize>(a: &mut Matrix<F, N, N>, q: &mut Matrix<F, N, N>, i: usize, j: usize, c: F) {
+    for k in 0..N {
+        let add = a[(j, k)] * c;
+        a[(i, k)] += add;
+    }
+    for m in [&mut *a, &mut *q] {
+        for k in 0..N {
+            let add = m[(k, j)] * c;
+            m[(k, i)] += add;
+        }
+    }
+}
+
+impl<const N: usize> BilinearForm<Real, N> {
+    /// Returns the number of positive and negative entries in a diagonal matrix congruent to A,
+    /// or `None` if the form is not symmetric. By Sylvester's law of inertia (6.38) these don't
+    /// depend on the diagonalization chosen. To allow for rounding, A counts as symmetric when it
+    /// agrees with Aᵗ up to the tolerance, in which case ½(A + Aᵗ) is diagonalized, and diagonal
+    /// entries within the tolerance of zero count as zero.
+    fn inertia(&self) -> Option<(usize, usize)> {
+        let a = self.0;
+        let tolerance = a.iter().fold(0.0, |max: Real, x| max.max(x.abs())) * TOLERANCE;
+        if (0..N).any(|i| (0..i).any(|j| (a[(i, j)] - a[(j, i)]).abs() > tolerance)) {
+            return None;
+        }
+        let (d, _) = Self::new((a + a.transpose()) / 2.0).diagonalize()?;
+        let tolerance = (0..N).fold(tolerance, |max, i| max.max(d[(i, i)].abs() * TOLERANCE));
+        let positive = (0..N).filter(|&i| d[(i, i)] > tolerance).count();
+        let negative = (0..N).filter(|&i| d[(i, i)] < -tolerance).count();
+        Some((positive, negative))
+    }
+
+    /// Returns the index of a symmetric real form, which is the number of positive diagonal
+    /// entries in any diagonal matrix representing it, or `None` if the form is not symmetric.
+    pub fn index(&self) -> Option<usize> {
+        self.inertia().map(|(positive, _)| positive)
+    }
+
+    /// Returns the signature of a symmetric real form, which is the number of positive diagonal
+    /// entries minus the number of negative ones in any diagonal matrix representing it,
+    /// or `None` if the form is not symmetric.
+    pub fn signature(&self) -> Option<isize> {
+        self.inertia().map(|(positive, negative)| positive as isize - negative as isize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BilinearForm;
+    use crate::field::{IntMod2, Rational};
+    use crate::vector_space::Tuple;
+    use crate::{tuple, matrix};
+
+    #[test]
+    fn bilinear_form_eval() {
+        // 6.8 Example 1: H(a, b) = 2a1b1 + 3a1b2 + 4a2b1 - a2b2
+        let h = BilinearForm::from_fn(|a: &Tuple<Rational, 2>, b: &Tuple<Rational, 2>| {
+            a[0] * b[0] * 2 + a[0] * b[1] * 3 + a[1] * b[0] * 4 - a[1] * b[1]
+        });
+        assert_eq!(*h.matrix(), matrix![[2, 3], [4, -1]]);
+        assert_eq!(h.eval(&tuple![1, 2], &tuple![3, -1]), Rational::from(29));
+        assert!(!h.is_symmetric());
+
+        let gamma = [tuple![1, 1], tuple![1, -1]];
+        let q = matrix![[1, 1], [1, -1]];
+        assert_eq!(h.matrix_relative_to(&gamma), *h.change_basis(&q).matrix());
+        assert_eq!(h.matrix_relative_to(&gamma), matrix![[8, 4], [2, -6]]);
+    }
+
+    #[test]
+    fn bilinear_form_classification() {
+        assert!(BilinearForm::new(matrix![[1, 2], [2, 3]]).is_symmetric());
+        assert!(BilinearForm::new(matrix![[0, 1], [-1, 0]]).is_alternating());
+        assert!(!BilinearForm::new(matrix![[1, 1], [-1, 0]]).is_alternating());
+    }
+
+    #[test]
+    fn bilinear_form_diagonalize() {
+        let h = BilinearForm::new(matrix![[1, -1, 3], [-1, 2, 1], [3, 1, 1]]);
+        let (d, q) = h.diagonalize().unwrap();
+        assert_eq!(*h.change_basis(&q).matrix(), d);
+        assert_eq!(d, matrix![[1, 0, 0], [0, 1, 0], [0, 0, -24]]);
+
+        let h = BilinearForm::new(matrix![[0, 1], [1, 0]]);
+        let (d, q) = h.diagonalize().unwrap();
+        assert_eq!(*h.change_basis(&q).matrix(), d);
+        assert_eq!(d[(0, 1)], Rational::from(0));
+
+        let (o, i) = (IntMod2::Zero, IntMod2::One);
+        assert_eq!(BilinearForm::new(matrix![[o, i], [i, o]]).diagonalize(), None);
+    }
+
+    #[test]
+    fn bilinear_form_inertia() {
+        let h = BilinearForm::new(matrix![[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 2.0]]);
+        assert_eq!(h.index(), Some(2));
+        assert_eq!(h.signature(), Some(1));
+        assert_eq!(h.rank(), 3);
+        let h = BilinearForm::new(matrix![[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!((h.index(), h.signature(), h.rank()), (Some(1), Some(1), 1));
+
+        let h = BilinearForm::new(matrix![[1.0, 2.0], [0.0, 1.0]]);
+        assert_eq!((h.index(), h.signature(), h.rank()), (None, None, 2));
+        assert_eq!(BilinearForm::new(matrix![[1, 2], [0, 0]]).rank(), 1);
+
+        // Symmetric up to rounding, and singular up to rounding.
+        let h = BilinearForm::new(matrix![[0.3, 0.1 + 0.2], [0.3, 0.3]]);
+        assert!(!h.is_symmetric());
+        assert_eq!((h.index(), h.signature(), h.rank()), (Some(1), Some(1), 1));
+
+        // Eliminating the first row leaves rounding residue in A_22, which must not be used as a pivot.
+        let h = BilinearForm::new(matrix![[0.1, 0.3, 0.0], [0.3, 0.9, 1.0], [0.0, 1.0, 0.0]]);
+        let (d, _) = h.diagonalize().unwrap();
+        assert!((0..3).all(|i| d[(i, i)].abs() < 10.0));
+        assert_eq!((h.index(), h.signature(), h.rank()), (Some(2), Some(1), 3));
+    }
+}
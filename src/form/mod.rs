@@ -0,0 +1,5 @@
+mod bilinear;
+mod quadratic;
+
+pub use bilinear::BilinearForm;
+pub use quadratic::QuadraticForm;
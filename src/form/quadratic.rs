@@ -0,0 +1,102 @@
+use crate::field::Field;
+use crate::vector_space::{Matrix, Tuple};
+use super::BilinearForm;
+
+/// A type representing a quadratic form K: F^N -> F over a field of characteristic other than two.
+/// The form is stored as the unique symmetric bilinear form H with K(x) = H(x, x).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuadraticForm<F: Field, const N: usize>(BilinearForm<F, N>);
+
+impl<F: Field, const N: usize> QuadraticForm<F, N> {
+    /// Creates the quadratic form K(x) = xᵗAx for a symmetric matrix A.
+    /// Returns `None` if A is not symmetric or the field has characteristic two.
+    pub fn new(matrix: Matrix<F, N, N>) -> Option<Self> {
+        let h = BilinearForm::new(matrix);
+        if !h.is_symmetric() || F::one() + F::one() == F::zero() {
+            return None;
+        }
+        Some(Self(h))
+    }
+
+    /// Creates the quadratic form K(x) = H(x, x) of a bilinear form H, whose symmetric matrix is ½(A + Aᵗ).
+    /// Returns `None` if the field has characteristic two, where ½ does not exist.
+    pub fn from_bilinear_form(h: &BilinearForm<F, N>) -> Option<Self> {
+        let two = F::one() + F::one();
+        if two == F::zero() {
+            return None;
+        }
+        let a = *h.matrix();
+        Some(Self(BilinearForm::new((a + a.transpose()) / two)))
+    }
+
+    /// Creates the quadratic form given by the closure, which the caller is trusted to have made quadratic.
+    /// The symmetric bilinear form is recovered by polarization, H(x, y) = ½[K(x + y) - K(x) - K(y)].
+    /// Returns `None` if the field has characteristic two, where ½ does not exist.
+    pub fn from_fn(k: impl Fn(&Tuple<F, N>) -> F) -> Option<Self> {
+        let two = F::one() + F::one();
+        if two == F::zero() {
+            return None;
+        }
+        Some(Self(BilinearForm::from_fn(|x, y| (k(&(x.clone() + y)) - k(x) - k(y)) / two)))
+    }
+
+    /// Evaluates K(x) = H(x, x).
+    pub fn eval(&self, x: &Tuple<F, N>) -> F {
+        self.0.eval(x, x)
+    }
+
+    /// Returns the symmetric bilinear form H with K(x) = H(x, x).
+    pub fn bilinear_form(&self) -> BilinearForm<F, N> {
+        self.0
+    }
+}
+
+// The symmetric bilinear form associated with a quadratic form.
+impl<F: Field, const N: usize> From<QuadraticForm<F, N>> for BilinearForm<F, N> {
+    fn from(k: QuadraticForm<F, N>) -> Self {
+        k.bilinear_form()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QuadraticForm;
+    use crate::field::{IntMod2, Rational};
+    use crate::form::BilinearForm;
+    use crate::vector_space::Tuple;
+    use crate::{tuple, matrix};
+
+    #[test]
+    fn quadratic_form_polarization() {
+        // K(t1, t2, t3) = 2t1² - t2² + 6t1t2 - 4t2t3
+        let k = QuadraticForm::from_fn(|t: &Tuple<Rational, 3>| {
+            t[0] * t[0] * 2 - t[1] * t[1] + t[0] * t[1] * 6 - t[1] * t[2] * 4
+        }).unwrap();
+        assert_eq!(*k.bilinear_form().matrix(), matrix![[2, 3, 0], [3, -1, -2], [0, -2, 0]]);
+        assert_eq!(k.eval(&tuple![1, 1, 1]), Rational::from(3));
+
+        let (o, i) = (IntMod2::Zero, IntMod2::One);
+        assert_eq!(QuadraticForm::from_fn(|t: &Tuple<IntMod2, 2>| t[0] * t[1]), None);
+        assert_eq!(QuadraticForm::new(matrix![[i, o], [o, i]]), None);
+    }
+
+    #[test]
+    fn quadratic_form_new() {
+        let k = QuadraticForm::new(matrix![[1, 2], [2, 1]]).unwrap();
+        assert_eq!(k.eval(&tuple![1, 1]), Rational::from(6));
+        assert_eq!(QuadraticForm::new(matrix![[1, 4], [0, 1]]), None);
+    }
+
+    #[test]
+    fn quadratic_form_conversions() {
+        let h = BilinearForm::new(matrix![[1, 4], [0, 1]]);
+        let k = QuadraticForm::from_bilinear_form(&h).unwrap();
+        assert_eq!(*k.bilinear_form().matrix(), matrix![[1, 2], [2, 1]]);
+        let x = tuple![3, -2];
+        assert_eq!(k.eval(&x), h.eval(&x, &x));
+        assert!(BilinearForm::from(k).is_symmetric());
+
+        let (o, i) = (IntMod2::Zero, IntMod2::One);
+        assert_eq!(QuadraticForm::from_bilinear_form(&BilinearForm::new(matrix![[i, i], [o, i]])), None);
+    }
+}
@@ -11,7 +11,7 @@ pub struct Line<const N: usize> {
 
 impl<const N: usize> Line<N> {
     /// Creates a new line with the given initial point and direction vector.
-    fn new(initial: Tuple<Real, N>, direction: Tuple<Real, N>) -> Self {
+    pub fn new(initial: Tuple<Real, N>, direction: Tuple<Real, N>) -> Self {
         Self {
             initial,
             direction
@@ -19,7 +19,7 @@ impl<const N: usize> Line<N> {
     }
 
     /// Creates a new line which starts at p1 and passes through p2 at t=1.
-    fn through_points(p1: Tuple<Real, N>, p2: Tuple<Real, N>) -> Self {
+    pub fn through_points(p1: Tuple<Real, N>, p2: Tuple<Real, N>) -> Self {
         let direction = p2 - &p1;
         let initial = p1;
         Self {
@@ -30,9 +30,19 @@ impl<const N: usize> Line<N> {
 
     /// Evalutates the paramateriazed version of the line at time t.
     /// Returns (self.direction)*t + self.inital.
-    fn eval_at(&self, t: Real) -> Tuple<Real, N> {
+    pub fn eval_at(&self, t: Real) -> Tuple<Real, N> {
         (self.direction.clone()*t) + &self.initial
     }
+
+    /// Returns the distance from the point p to the line, which is the length of the
+    /// component of p - initial orthogonal to the direction.
+    pub fn distance_to(&self, p: &Tuple<Real, N>) -> Real {
+        let x = p.clone() - &self.initial;
+        match x.project_onto(&self.direction) {
+            Some(projection) => (x - projection).length(),
+            None => x.length(),
+        }
+    }
 }
 
 impl<const N: usize> fmt::Display for Line<N> {
@@ -62,5 +72,12 @@ mod tests {
 
         assert_eq!(Line::through_points(tuple![-2.0, -1.0, 5.0], tuple![3.0, 9.0, 7.0]),
                               Line::new(tuple![-2.0, -1.0, 5.0], tuple![5.0, 10.0, 2.0]));
+    }
+
+    #[test]
+    fn line_distance_to_point() {
+        let line = Line::new(tuple![1.0, 1.0, 0.0], tuple![2.0, 0.0, 0.0]);
+        assert_eq!(line.distance_to(&tuple![5.0, 4.0, 4.0]), 5.0);
+        assert_eq!(line.distance_to(&tuple![-3.0, 1.0, 0.0]), 0.0);
     }   
 }
\ No newline at end of file
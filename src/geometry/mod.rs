@@ -1,2 +1,6 @@
 mod line;
-mod plane;
\ No newline at end of file
+mod plane;
+mod vector;
+
+pub use line::Line;
+pub use plane::Plane;
@@ -1,5 +1,6 @@
 use crate::field::Real;
-use crate::vector_space::Tuple;
+use crate::vector_space::{ExteriorPower, Tuple};
+use crate::norm::Normed;
 use std::fmt;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -14,7 +15,7 @@ pub struct Plane<const N: usize> {
 impl<const N: usize> Plane<N> {
     /// Creates a new plane with the given initial point and direction vectors.
     /// Implicitly assumes that the two direction vectors are not paralllel.
-    fn new(initial: Tuple<Real, N>, direction_1: Tuple<Real, N>, direction_2: Tuple<Real, N>) -> Self {
+    pub fn new(initial: Tuple<Real, N>, direction_1: Tuple<Real, N>, direction_2: Tuple<Real, N>) -> Self {
         Self {
             initial,
             direction_1,
@@ -23,7 +24,7 @@ impl<const N: usize> Plane<N> {
     }
 
     /// Creates a new plane which passes through p1 at (t=0,s=0), p2 at (t=1,s=0), and p3 at (t=0,s=1).
-    fn through_points(p1: Tuple<Real, N>, p2: Tuple<Real, N>, p3: Tuple<Real, N>) -> Self {
+    pub fn through_points(p1: Tuple<Real, N>, p2: Tuple<Real, N>, p3: Tuple<Real, N>) -> Self {
         let direction_1 = p2 - &p1;
         let direction_2 = p3 - &p1;
         let initial = p1;
@@ -36,9 +37,48 @@ impl<const N: usize> Plane<N> {
 
     /// Evalutates the paramateriazed version of the line at paramaters t,s.
     /// Returns (self.direction_1)*t + (self.direction_2)*s + self.inital.
-    fn eval_at(&self, t: Real, s: Real) -> Tuple<Real, N> {
+    pub fn eval_at(&self, t: Real, s: Real) -> Tuple<Real, N> {
         (self.direction_1.clone()*t) + (self.direction_2.clone()*s) + &self.initial
     }
+
+    /// Returns the Plücker coordinates of the plane's direction, which are the coefficients of
+    /// direction_1 ∧ direction_2. Two planes are parallel exactly when these are proportional.
+    pub fn plucker_coordinates(&self) -> ExteriorPower<Real, N, 2> {
+        ExteriorPower::from_vectors(&[self.direction_1.clone(), self.direction_2.clone()])
+    }
+
+    /// Returns the area of the parallelogram spanned by the two direction vectors,
+    /// which is the norm of their wedge product.
+    pub fn area(&self) -> Real {
+        self.plucker_coordinates().norm()
+    }
+}
+
+impl Plane<3> {
+    /// Returns the normal vector direction_1 × direction_2 of a plane in 3-dimensional space.
+    pub fn normal(&self) -> Tuple<Real, 3> {
+        self.direction_1.cross(&self.direction_2)
+    }
+
+    /// Returns the distance from the point p to the plane, which is the length of the
+    /// projection of p - initial onto the normal.
+    pub fn distance_to(&self, p: &Tuple<Real, 3>) -> Real {
+        let n = self.normal();
+        (p.clone() - &self.initial).dot(&n).abs() / n.length()
+    }
+
+    /// Returns 1, -1 or 0 as the point p lies on the side of the plane the normal points to,
+    /// the other side, or on the plane itself.
+    pub fn orientation(&self, p: &Tuple<Real, 3>) -> i8 {
+        let side = (p.clone() - &self.initial).triple_product(&self.direction_1, &self.direction_2);
+        if side > 0.0 {
+            1
+        } else if side < 0.0 {
+            -1
+        } else {
+            0
+        }
+    }
 }
 
 impl<const N: usize> fmt::Display for Plane<N> {
@@ -50,6 +90,7 @@ impl<const N: usize> fmt::Display for Plane<N> {
 #[cfg(test)]
 mod tests {
     use super::Plane;
+    use crate::vector_space::{ExteriorPower, FiniteDimensional};
     use crate::tuple;
 
     #[test]
@@ -65,5 +106,23 @@ mod tests {
 
         assert_eq!(Plane::through_points(tuple![1.0, 1.0, 1.0], tuple![5.0, 5.0, 5.0], tuple![-6.0, 4.0, 2.0]),
                               Plane::new(tuple![1.0, 1.0, 1.0], tuple![4.0, 4.0, 4.0], tuple![-7.0, 3.0, 1.0]));
+    }
+
+    #[test]
+    fn plane_plucker_coordinates() {
+        let plane = Plane::new(tuple![1.0, 1.0, 1.0], tuple![1.0, 2.0, 3.0], tuple![4.0, 5.0, 6.0]);
+        assert_eq!(plane.plucker_coordinates(), ExteriorPower::from_entries(&[-3.0, -6.0, -3.0]));
+        assert_eq!(plane.area(), 54.0f64.sqrt());
+        assert_eq!(Plane::new(tuple![0.0, 0.0, 0.0, 5.0], tuple![3.0, 0.0, 0.0, 0.0], tuple![0.0, 0.0, 2.0, 0.0]).area(), 6.0);
+    }
+
+    #[test]
+    fn plane_normal_and_distance() {
+        let plane = Plane::through_points(tuple![0.0, 0.0, 1.0], tuple![1.0, 0.0, 1.0], tuple![0.0, 1.0, 1.0]);
+        assert_eq!(plane.normal(), tuple![0.0, 0.0, 1.0]);
+        assert_eq!(plane.distance_to(&tuple![3.0, -2.0, 4.0]), 3.0);
+        assert_eq!(plane.orientation(&tuple![3.0, -2.0, 4.0]), 1);
+        assert_eq!(plane.orientation(&tuple![0.0, 0.0, -1.0]), -1);
+        assert_eq!(plane.orientation(&tuple![5.0, 5.0, 1.0]), 0);
     }   
 }
\ No newline at end of file
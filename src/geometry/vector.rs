@@ -0,0 +1,118 @@
+use crate::field::{ConjugateField, Field, Real};
+use crate::vector_space::Tuple;
+use crate::inner_product::InnerProductSpace;
+
+// Euclidean operations on F^N, using the standard inner product x · y = Σ x_i conj(y_i).
+impl<F: ConjugateField, const N: usize> Tuple<F, N> {
+    /// Returns the dot product x · y = Σ x_i conj(y_i), which is the standard inner product ⟨x, y⟩.
+    /// Over the reals this is Σ x_i y_i.
+    pub fn dot(&self, other: &Self) -> F {
+        self.inner(other)
+    }
+
+    /// Returns the length |x| = sqrt(x · x), which is the Euclidean 2-norm.
+    pub fn length(&self) -> Real {
+        self.norm_2()
+    }
+
+    /// Returns the angle between x and y in [0, π], defined by cos θ = Re(x · y) / (|x||y|),
+    /// or `None` if either vector is zero.
+    pub fn angle(&self, other: &Self) -> Option<Real> {
+        let lengths = self.length() * other.length();
+        if lengths == 0.0 {
+            return None;
+        }
+        Some((self.dot(other).real_part() / lengths).clamp(-1.0, 1.0).acos())
+    }
+
+    /// Returns the projection ((x · u) / (u · u)) u of x onto the line spanned by u,
+    /// or `None` if u is zero.
+    pub fn project_onto(&self, u: &Self) -> Option<Self> {
+        let uu = u.dot(u);
+        if uu == F::zero() {
+            return None;
+        }
+        Some(u.clone() * (self.dot(u) / uu))
+    }
+}
+
+impl<F: ConjugateField + From<Real>, const N: usize> Tuple<F, N> {
+    /// Returns the unit vector x / |x| in the direction of x, or `None` if x is zero.
+    pub fn normalize(&self) -> Option<Self> {
+        let length = self.length();
+        if length == 0.0 {
+            return None;
+        }
+        Some(self.clone() / F::from(length))
+    }
+}
+
+impl<F: Field> Tuple<F, 3> {
+    /// Returns the cross product x × y, which is orthogonal to both x and y.
+    pub fn cross(&self, other: &Self) -> Self {
+        Tuple::from([
+            self[1] * other[2] - self[2] * other[1],
+            self[2] * other[0] - self[0] * other[2],
+            self[0] * other[1] - self[1] * other[0],
+        ])
+    }
+
+    /// Returns the scalar triple product a · (b × c), which is the determinant of the matrix with rows a, b, c.
+    /// Its absolute value is the volume of the parallelepiped spanned by a, b and c,
+    /// and it is positive exactly when (a, b, c) is right-handed.
+    pub fn triple_product(&self, b: &Self, c: &Self) -> F {
+        let bc = b.cross(c);
+        self[0] * bc[0] + self[1] * bc[1] + self[2] * bc[2]
+    }
+
+    /// Returns the vector triple product a × (b × c) = (a · c)b - (a · b)c.
+    pub fn vector_triple_product(&self, b: &Self, c: &Self) -> Self {
+        self.cross(&b.cross(c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::field::Rational;
+    use crate::vector_space::determinant;
+    use crate::{tuple, cmplx};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn dot_length_angle() {
+        let x = tuple![1.0, 2.0, 2.0];
+        assert_eq!(x.dot(&tuple![2.0, 0.0, -1.0]), 0.0);
+        assert_eq!(x.length(), 3.0);
+        assert_eq!(x.normalize(), Some(tuple![1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0]));
+        assert_eq!(tuple![0.0, 0.0].normalize(), None);
+        assert!((tuple![1.0, 0.0].angle(&tuple![1.0, 1.0]).unwrap() - PI / 4.0).abs() < 1e-12);
+        assert_eq!(tuple![1.0, 0.0].angle(&tuple![-2.0, 0.0]), Some(PI));
+        assert_eq!(tuple![1.0, 0.0].angle(&tuple![0.0, 0.0]), None);
+
+        let z = tuple![cmplx!(1, 1), cmplx!(0, 2)];
+        assert_eq!(z.dot(&z), cmplx!(6));
+        assert_eq!(z.dot(&tuple![cmplx!(0, 1), cmplx!(1)]), cmplx!(1, 1));
+    }
+
+    #[test]
+    fn projection() {
+        let x = tuple![2, 3, 1];
+        let u = tuple![1, 1, 0];
+        let p = x.project_onto(&u).unwrap();
+        assert_eq!(p, tuple![Rational::from(5) / Rational::from(2), Rational::from(5) / Rational::from(2), Rational::from(0)]);
+        assert_eq!((x - p).dot(&u), Rational::from(0));
+        assert_eq!(u.project_onto(&tuple![0, 0, 0]), None);
+    }
+
+    #[test]
+    fn cross_and_triple_products() {
+        let (a, b, c) = (tuple![1, 2, 3], tuple![4, 5, 6], tuple![1, 0, 2]);
+        assert_eq!(a.cross(&b), tuple![-3, 6, -3]);
+        assert_eq!(a.cross(&b).dot(&a), Rational::from(0));
+        assert_eq!(b.cross(&a), -a.cross(&b));
+        assert_eq!(tuple![1, 0, 0].cross(&tuple![0, 1, 0]), tuple![0, 0, 1]);
+        assert_eq!(a.triple_product(&b, &c), determinant(&[a.clone(), b.clone(), c.clone()]));
+        assert_eq!(a.triple_product(&b, &c), Rational::from(-9));
+        assert_eq!(a.vector_triple_product(&b, &c), b.clone() * a.dot(&c) - c.clone() * a.dot(&b));
+    }
+}
@@ -0,0 +1,163 @@
+use crate::field::{ConjugateField, Real};
+use crate::vector_space::{VectorSpace, FiniteDimensional};
+use super::InnerProduct;
+
+/// Over inexact fields, a vector v left over from w is treated as zero when ⟨v, v⟩ is at most this
+/// times ⟨w, w⟩, so that ||v|| is at most 1e-10 ||w||.
+const TOLERANCE: Real = 1e-20;
+
+/// Returns the component of x along v, which is (⟨x, v⟩ / ||v||²)v.
+fn component<F: ConjugateField, V: VectorSpace<F> + Clone>(ip: &impl InnerProduct<F, V>, x: &V, v: &V) -> V {
+    v.clone() * (ip.inner(x, v) / ip.inner(v, v))
+}
+
+/// Returns whether the vector v left over after removing components from w is zero.
+/// Over exact fields this means ⟨v, v⟩ = 0, and over inexact fields that ⟨v, v⟩ is negligible next to ⟨w, w⟩.
+fn is_negligible<F: ConjugateField, V>(ip: &impl InnerProduct<F, V>, v: &V, w: &V) -> bool {
+    let vv = ip.inner(v, v);
+    match vv.magnitude() {
+        None => vv == F::zero(),
+        Some(size) => size <= TOLERANCE * ip.inner(w, w).real_part(),
+    }
+}
+
+/// Applies the Gram-Schmidt process (6.4) to a list of vectors, returning an orthogonal list with
+/// the same span. Each v_k is w_k minus its components along the previous v_j.
+/// Vectors which are linear combinations of the previous ones reduce to zero and are left out;
+/// over `Real` and `Complex`, this includes those reducing to rounding errors.
+/// Over `Rational` the result is exact, since no square roots are taken.
+pub fn gram_schmidt<F: ConjugateField, V: VectorSpace<F> + Clone>(ip: &impl InnerProduct<F, V>, vectors: &[V]) -> Vec<V> {
+    let mut orthogonal: Vec<V> = Vec::new();
+    for w in vectors.iter() {
+        let mut v = w.clone();
+        for u in orthogonal.iter() {
+            v -= component(ip, w, u);
+        }
+        if !is_negligible(ip, &v, w) {
+            orthogonal.push(v);
+        }
+    }
+    orthogonal
+}
+
+/// Applies the modified Gram-Schmidt process, which gives the same result as `gram_schmidt` in exact
+/// arithmetic but is more stable for floating point. As soon as each v_k is found, its component
+/// is removed from all of the remaining vectors.
+pub fn modified_gram_schmidt<F: ConjugateField, V: VectorSpace<F> + Clone>(ip: &impl InnerProduct<F, V>, vectors: &[V]) -> Vec<V> {
+    let mut remaining = vectors.to_vec();
+    let mut orthogonal = Vec::new();
+    for k in 0..remaining.len() {
+        let v = remaining[k].clone();
+        if is_negligible(ip, &v, &vectors[k]) {
+            continue;
+        }
+        for w in remaining[k+1..].iter_mut() {
+            let c = component(ip, w, &v);
+            *w -= c;
+        }
+        orthogonal.push(v);
+    }
+    orthogonal
+}
+
+/// Returns an orthonormal basis for the span of the given vectors, by normalizing the result of
+/// the modified Gram-Schmidt process.
+pub fn orthonormal_basis<F: ConjugateField + From<Real>, V: VectorSpace<F> + Clone>(ip: &impl InnerProduct<F, V>, vectors: &[V]) -> Vec<V> {
+    modified_gram_schmidt(ip, vectors).into_iter()
+        .map(|v| {
+            let norm = ip.norm(&v);
+            v / F::from(norm)
+        })
+        .collect()
+}
+
+/// Returns the Fourier coefficients ⟨x, v_i⟩ of x relative to an orthonormal set.
+pub fn fourier_coefficients<F: ConjugateField, V>(ip: &impl InnerProduct<F, V>, x: &V, orthonormal: &[V]) -> Vec<F> {
+    orthonormal.iter().map(|v| ip.inner(x, v)).collect()
+}
+
+/// Returns the orthogonal projection of x onto the span W of the given vectors, which is the
+/// unique vector u in W with x - u in W^⊥ (6.6).
+pub fn orthogonal_projection<F: ConjugateField, V: VectorSpace<F> + Clone>(ip: &impl InnerProduct<F, V>, x: &V, spanning: &[V]) -> V {
+    let mut projection = V::zero();
+    for v in gram_schmidt(ip, spanning).iter() {
+        projection += component(ip, x, v);
+    }
+    projection
+}
+
+/// Returns an orthogonal basis for the orthogonal complement W^⊥ = {x : ⟨x, y⟩ = 0 for all y in W}
+/// of the span W of the given vectors. This is found by applying the Gram-Schmidt process to a
+/// basis of W followed by the standard basis, and keeping the vectors found after W is exhausted.
+pub fn orthogonal_complement<F: ConjugateField, V: FiniteDimensional<F>>(ip: &impl InnerProduct<F, V>, spanning: &[V]) -> Vec<V> {
+    let dim = gram_schmidt(ip, spanning).len();
+    let mut vectors = spanning.to_vec();
+    vectors.extend(V::standard_basis());
+    gram_schmidt(ip, &vectors).split_off(dim)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{gram_schmidt, modified_gram_schmidt, orthonormal_basis, fourier_coefficients, orthogonal_projection, orthogonal_complement};
+    use crate::field::{Rational, Real};
+    use crate::inner_product::{InnerProduct, Standard, Integral};
+    use crate::vector_space::{BoundedPolynom, Tuple};
+    use crate::{tuple, polynom};
+
+    #[test]
+    fn gram_schmidt_exact() {
+        // 6.2 Example 4
+        let w = [tuple![1, 0, 1, 0], tuple![1, 1, 1, 1], tuple![0, 1, 2, 1]];
+        let expected = vec![tuple![1, 0, 1, 0], tuple![0, 1, 0, 1], tuple![-1, 0, 1, 0]];
+        assert_eq!(gram_schmidt(&Standard, &w), expected);
+        assert_eq!(modified_gram_schmidt(&Standard, &w), expected);
+        assert_eq!(gram_schmidt(&Standard, &[tuple![1, 1], tuple![2, 2]]), vec![tuple![1, 1]]);
+    }
+
+    #[test]
+    fn gram_schmidt_legendre() {
+        // Orthogonalizing {1, x, x^2} in P_2(R) with ⟨f, g⟩ = ∫₋₁¹ f(t)g(t) dt gives the Legendre polynomials up to scaling.
+        let basis: Vec<BoundedPolynom<Real, 2>> = vec![
+            BoundedPolynom::new(polynom![1.0]).unwrap(),
+            BoundedPolynom::new(polynom![0.0, 1.0]).unwrap(),
+            BoundedPolynom::new(polynom![0.0, 0.0, 1.0]).unwrap(),
+        ];
+        let orthogonal = gram_schmidt(&Integral::new(-1.0, 1.0), &basis);
+        assert_eq!(orthogonal[1], basis[1]);
+        let p2 = orthogonal[2].polynom();
+        assert!((p2[0] + 1.0 / 3.0).abs() < 1e-12 && p2[1] == 0.0 && p2[2] == 1.0);
+    }
+
+    #[test]
+    fn gram_schmidt_orthonormal() {
+        let basis = orthonormal_basis(&Standard, &[tuple![3.0, 4.0], tuple![1.0, 0.0]]);
+        assert_eq!(basis[0], tuple![0.6, 0.8]);
+        let coefficients = fourier_coefficients(&Standard, &tuple![1.0, 2.0], &basis);
+        assert!((coefficients[0] - 2.2).abs() < 1e-12);
+        assert!((coefficients[1] + 0.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn gram_schmidt_projection_and_complement() {
+        let w = [tuple![1, 0, 1, 0], tuple![0, 1, 0, 1]];
+        assert_eq!(orthogonal_projection(&Standard, &tuple![1, 2, 3, 4], &w), tuple![2, 3, 2, 3]);
+
+        let complement: Vec<Tuple<Rational, 3>> = orthogonal_complement(&Standard, &[tuple![1, 1, 0]]);
+        assert_eq!(complement, vec![tuple![1, -1, 0] / 2, tuple![0, 0, 1]]);
+
+        let w = [tuple![1.0, 2.0, 3.0]];
+        let complement = orthogonal_complement(&Standard, &w);
+        assert_eq!(complement.len(), 2);
+        for v in complement.iter() {
+            assert!(Standard.inner(v, &w[0]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn gram_schmidt_inexact_dependence() {
+        let w = [tuple![0.1, 0.2, 0.3], tuple![0.3, 0.6, 0.9], tuple![1.0, 0.0, 0.0]];
+        assert_eq!(gram_schmidt(&Standard, &w).len(), 2);
+        assert_eq!(modified_gram_schmidt(&Standard, &w).len(), 2);
+        assert_eq!(orthonormal_basis(&Standard, &w).len(), 2);
+    }
+}
@@ -0,0 +1,193 @@
+mod gram_schmidt;
+
+pub use gram_schmidt::{gram_schmidt, modified_gram_schmidt, orthonormal_basis, fourier_coefficients, orthogonal_projection, orthogonal_complement};
+
+use crate::field::{ConjugateField, Real};
+use crate::vector_space::{VectorSpace, Entries, Tuple, Matrix, Polynom, BoundedPolynom};
+use crate::norm::Normed;
+
+/// A trait for vector spaces with a standard inner product ⟨x, y⟩.
+/// The inner product must be linear in its first argument, satisfy ⟨x, y⟩ = conj(⟨y, x⟩),
+/// and have ⟨x, x⟩ > 0 for all x ≠ 0.
+/// The norm from `Normed` must be the induced norm ||x|| = sqrt(⟨x, x⟩).
+pub trait InnerProductSpace<F: ConjugateField>: VectorSpace<F> + Normed {
+    /// Returns the inner product ⟨self, other⟩.
+    fn inner(&self, other: &Self) -> F;
+
+    /// Returns whether ⟨x, y⟩ = 0.
+    fn is_orthogonal(&self, other: &Self) -> bool {
+        self.inner(other) == F::zero()
+    }
+}
+
+/// A trait for inner products on a vector space V, which allows other inner products than the
+/// standard one to be used. The same properties as for `InnerProductSpace::inner` must hold.
+pub trait InnerProduct<F: ConjugateField, V> {
+    /// Returns the inner product ⟨x, y⟩.
+    fn inner(&self, x: &V, y: &V) -> F;
+
+    /// Returns the induced norm ||x|| = sqrt(⟨x, x⟩).
+    fn norm(&self, x: &V) -> Real {
+        self.inner(x, x).real_part().sqrt()
+    }
+
+    /// Returns whether ⟨x, y⟩ = 0.
+    fn is_orthogonal(&self, x: &V, y: &V) -> bool {
+        self.inner(x, y) == F::zero()
+    }
+}
+
+/// The standard inner product of an inner product space.
+pub struct Standard;
+
+impl<F: ConjugateField, V: InnerProductSpace<F>> InnerProduct<F, V> for Standard {
+    fn inner(&self, x: &V, y: &V) -> F {
+        x.inner(y)
+    }
+}
+
+// Any closure taking two vectors to a scalar, which the caller is trusted to have made an inner product.
+impl<F: ConjugateField, V, T: Fn(&V, &V) -> F> InnerProduct<F, V> for T {
+    fn inner(&self, x: &V, y: &V) -> F {
+        self(x, y)
+    }
+}
+
+/// The weighted inner product ⟨x, y⟩ = Σ w_i x_i conj(y_i) on F^N. All weights must be positive.
+pub struct Weighted<F: ConjugateField, const N: usize>(pub Tuple<F, N>);
+
+impl<F: ConjugateField, const N: usize> InnerProduct<F, Tuple<F, N>> for Weighted<F, N> {
+    fn inner(&self, x: &Tuple<F, N>, y: &Tuple<F, N>) -> F {
+        let mut accum = F::zero();
+        for i in 0..N {
+            accum += self.0[i] * x[i] * y[i].conj();
+        }
+        accum
+    }
+}
+
+/// The inner product ⟨f, g⟩ = ∫ f(t)g(t) dt over the interval [a, b] on real polynomials.
+pub struct Integral {
+    pub a: Real,
+    pub b: Real,
+}
+
+impl Integral {
+    pub fn new(a: Real, b: Real) -> Self {
+        Self {
+            a,
+            b
+        }
+    }
+}
+
+impl InnerProduct<Real, Polynom<Real>> for Integral {
+    fn inner(&self, f: &Polynom<Real>, g: &Polynom<Real>) -> Real {
+        let (f, g) = (f.entries(), g.entries());
+        let mut accum = 0.0;
+        for (i, &fi) in f.iter().enumerate() {
+            for (j, &gj) in g.iter().enumerate() {
+                let power = (i + j + 1) as i32;
+                accum += fi * gj * (self.b.powi(power) - self.a.powi(power)) / power as Real;
+            }
+        }
+        accum
+    }
+}
+impl<const N: usize> InnerProduct<Real, BoundedPolynom<Real, N>> for Integral {
+    fn inner(&self, f: &BoundedPolynom<Real, N>, g: &BoundedPolynom<Real, N>) -> Real {
+        self.inner(f.polynom(), g.polynom())
+    }
+}
+
+// The standard inner product ⟨x, y⟩ = Σ x_i conj(y_i) on F^N.
+impl<F: ConjugateField, const N: usize> InnerProductSpace<F> for Tuple<F, N> {
+    fn inner(&self, other: &Self) -> F {
+        let mut accum = F::zero();
+        for i in 0..N {
+            accum += self[i] * other[i].conj();
+        }
+        accum
+    }
+}
+
+// The Frobenius inner product ⟨A, B⟩ = tr(B*A) = Σ A_ij conj(B_ij).
+impl<F: ConjugateField, const M: usize, const N: usize> InnerProductSpace<F> for Matrix<F, M, N> {
+    fn inner(&self, other: &Self) -> F {
+        let mut accum = F::zero();
+        for i in 0..M {
+            for j in 0..N {
+                accum += self[(i, j)] * other[(i, j)].conj();
+            }
+        }
+        accum
+    }
+}
+
+// The standard inner product on real polynomials is ⟨f, g⟩ = ∫₀¹ f(t)g(t) dt.
+impl InnerProductSpace<Real> for Polynom<Real> {
+    fn inner(&self, other: &Self) -> Real {
+        Integral::new(0.0, 1.0).inner(self, other)
+    }
+}
+impl<const N: usize> InnerProductSpace<Real> for BoundedPolynom<Real, N> {
+    fn inner(&self, other: &Self) -> Real {
+        Integral::new(0.0, 1.0).inner(self, other)
+    }
+}
+impl Normed for Polynom<Real> {
+    fn norm(&self) -> Real {
+        self.inner(self).sqrt()
+    }
+}
+impl<const N: usize> Normed for BoundedPolynom<Real, N> {
+    fn norm(&self) -> Real {
+        self.inner(self).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InnerProductSpace, InnerProduct, Integral, Weighted};
+    use crate::field::Real;
+    use crate::norm::Normed;
+    use crate::vector_space::{Polynom, Tuple};
+    use crate::{tuple, matrix, polynom, cmplx};
+
+    #[test]
+    fn inner_product_tuples() {
+        // 6.1 Example 1
+        let x = tuple![cmplx!(1, 1), cmplx!(4)];
+        let y = tuple![cmplx!(2, -3), cmplx!(4, 5)];
+        assert_eq!(x.inner(&y), cmplx!(15, -15));
+        assert_eq!(y.inner(&x), cmplx!(15, 15));
+        assert_eq!(tuple![3.0, 4.0].norm(), 5.0);
+        assert!(tuple![1, 2, -1].is_orthogonal(&tuple![1, 0, 1]));
+    }
+
+    #[test]
+    fn inner_product_matrices() {
+        let a = matrix![[cmplx!(1), cmplx!(2, 1)], [cmplx!(3), cmplx!(0, 1)]];
+        let b = matrix![[cmplx!(1, 1), cmplx!(0)], [cmplx!(0, 1), cmplx!(0, -1)]];
+        assert_eq!(a.inner(&b), cmplx!(0, -4));
+        assert_eq!(matrix![[1.0, 1.0], [1.0, 1.0]].norm(), 2.0);
+    }
+
+    #[test]
+    fn inner_product_polynomials() {
+        let f: Polynom<Real> = polynom![0.0, 1.0];
+        let g: Polynom<Real> = polynom![0.0, 0.0, 1.0];
+        assert_eq!(f.inner(&g), 0.25);
+        assert_eq!(Integral::new(-1.0, 1.0).inner(&f, &g), 0.0);
+        assert!(Integral::new(-1.0, 1.0).is_orthogonal(&f, &g));
+        assert_eq!(f.norm(), (1.0f64 / 3.0).sqrt());
+    }
+
+    #[test]
+    fn inner_product_user_defined() {
+        let weighted = Weighted(tuple![1.0, 2.0]);
+        assert_eq!(weighted.inner(&tuple![1.0, 1.0], &tuple![1.0, 1.0]), 3.0);
+        let closure = |x: &Tuple<Real, 2>, y: &Tuple<Real, 2>| 2.0 * x[0] * y[0] + x[1] * y[1];
+        assert_eq!(closure.norm(&tuple![1.0, 2.0]), 6.0f64.sqrt());
+    }
+}
@@ -1,6 +1,10 @@
 pub mod vector_space;
 pub mod field;
 pub mod geometry;
+pub mod linear_map;
+pub mod inner_product;
+pub mod norm;
+pub mod form;
 
 #[cfg(test)]
 mod tests {
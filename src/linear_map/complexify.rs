@@ -0,0 +1,49 @@
+use crate::field::{Complex, Real};
+use crate::vector_space::{Complexify, Realification, VectorSpace};
+use super::LinearMap;
+
+/// The complexification T_C: V_C -> W_C of a linear map T: V -> W between real vector spaces,
+/// defined by T_C(u + iv) = T(u) + iT(v). It has the same standard matrix as T, so a real operator
+/// with no real eigenvalues can have its complex eigenvalues and eigenvectors found through T_C.
+pub struct ComplexifiedMap<T>(pub T);
+
+impl<V: VectorSpace<Real> + Clone, W: VectorSpace<Real> + Clone, T: LinearMap<Real, V, W>> LinearMap<Complex, Complexify<V>, Complexify<W>> for ComplexifiedMap<T> {
+    fn apply(&self, x: &Complexify<V>) -> Complexify<W> {
+        Complexify::new(self.0.apply(x.real()), self.0.apply(x.imaginary()))
+    }
+}
+
+/// The realification T_R: V_R -> W_R of a linear map T: V -> W between complex vector spaces, which is
+/// T itself with scalars restricted to the reals. Each complex entry a + ib of the standard matrix of T
+/// becomes the 2 × 2 block [[a, -b], [b, a]] in the standard matrix of T_R.
+pub struct RealifiedMap<T>(pub T);
+
+impl<V, W, T: LinearMap<Complex, V, W>> LinearMap<Real, Realification<V>, Realification<W>> for RealifiedMap<T> {
+    fn apply(&self, x: &Realification<V>) -> Realification<W> {
+        Realification(self.0.apply(&x.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComplexifiedMap, RealifiedMap};
+    use crate::linear_map::LinearMap;
+    use crate::vector_space::{Complexify, Realification};
+    use crate::{tuple, matrix, cmplx};
+
+    #[test]
+    fn complexified_rotation() {
+        // Rotation by a right angle has no real eigenvalues, but its complexification has eigenvalues ±i.
+        let rotation = ComplexifiedMap(matrix![[0.0, -1.0], [1.0, 0.0]]);
+        let x = Complexify::new(tuple![1.0, 0.0], tuple![0.0, -1.0]);
+        assert_eq!(rotation.apply(&x), x.clone() * cmplx!(0, 1));
+        assert_eq!(rotation.apply(&x.conjugate()), x.conjugate() * cmplx!(0, -1));
+    }
+
+    #[test]
+    fn realified_matrix() {
+        let t = RealifiedMap(matrix![[cmplx!(1, 2)]]);
+        assert_eq!(t.standard_rows(), vec![vec![1.0, -2.0], vec![2.0, 1.0]]);
+        assert_eq!(t.apply(&Realification(tuple![cmplx!(0, 1)])), Realification(tuple![cmplx!(-2, 1)]));
+    }
+}
@@ -0,0 +1,32 @@
+use crate::field::Field;
+use crate::vector_space::{BoundedPolynom, Polynom};
+use super::LinearMap;
+use std::marker::PhantomData;
+
+/// The differentiation operator T(f) = f' on a space of polynomials V.
+pub struct Derivative<V>(PhantomData<V>);
+
+impl<V> Derivative<V> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<V> Default for Derivative<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Field> LinearMap<F, Polynom<F>, Polynom<F>> for Derivative<Polynom<F>> {
+    fn apply(&self, f: &Polynom<F>) -> Polynom<F> {
+        f.derivative()
+    }
+}
+
+// Differentiation lowers the degree, so P_N(F) is mapped into itself.
+impl<F: Field, const N: usize> LinearMap<F, BoundedPolynom<F, N>, BoundedPolynom<F, N>> for Derivative<BoundedPolynom<F, N>> {
+    fn apply(&self, f: &BoundedPolynom<F, N>) -> BoundedPolynom<F, N> {
+        BoundedPolynom::new(f.polynom().derivative()).unwrap()
+    }
+}
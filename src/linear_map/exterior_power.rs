@@ -0,0 +1,53 @@
+use crate::field::Field;
+use crate::vector_space::{ExteriorPower, Entries, FiniteDimensional, Tuple, VectorSpace, subsets};
+use super::LinearMap;
+
+/// The map Λᴷ(T): Λᴷ(F^N) -> Λᴷ(F^M) induced by a linear map T: F^N -> F^M, which is the unique
+/// linear map with Λᴷ(T)(v_1 ∧ ... ∧ v_K) = T(v_1) ∧ ... ∧ T(v_K).
+/// For T = L_A its standard matrix is the K'th compound matrix of A, made up of the K × K minors of A,
+/// and Λᴺ(A) is multiplication by det(A).
+pub struct ExteriorPowerMap<T, const K: usize>(pub T);
+
+impl<F: Field, const N: usize, const M: usize, const K: usize, T: LinearMap<F, Tuple<F, N>, Tuple<F, M>>> LinearMap<F, ExteriorPower<F, N, K>, ExteriorPower<F, M, K>> for ExteriorPowerMap<T, K> {
+    fn apply(&self, x: &ExteriorPower<F, N, K>) -> ExteriorPower<F, M, K> {
+        let images: Vec<Tuple<F, M>> = Tuple::<F, N>::standard_basis().iter().map(|e| self.0.apply(e)).collect();
+        let mut out = <ExteriorPower<F, M, K> as VectorSpace<F>>::zero();
+        for (s, c) in subsets(N, K).iter().zip(x.entries()) {
+            let vectors: Vec<Tuple<F, M>> = s.iter().map(|&i| images[i].clone()).collect();
+            out += ExteriorPower::from_slice(&vectors) * c;
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExteriorPowerMap;
+    use crate::field::Rational;
+    use crate::linear_map::LinearMap;
+    use crate::vector_space::{ExteriorPower, determinant};
+    use crate::{tuple, matrix};
+
+    #[test]
+    fn exterior_power_map_wedge() {
+        let a = matrix![[1, 2, 0], [0, 1, -1], [3, 0, 1]];
+        let (u, v) = (tuple![1, 0, 2], tuple![-1, 1, 1]);
+        let uv = ExteriorPower::from_vectors(&[u.clone(), v.clone()]);
+        assert_eq!(ExteriorPowerMap::<_, 2>(a).apply(&uv), ExteriorPower::from_vectors(&[a.apply(&u), a.apply(&v)]));
+    }
+
+    #[test]
+    fn exterior_power_map_compound() {
+        let a = matrix![[1, 2], [3, 4], [5, 6]];
+        assert_eq!(ExteriorPowerMap::<_, 2>(a).standard_rows(), vec![
+            vec![Rational::from(-2)],
+            vec![Rational::from(-4)],
+            vec![Rational::from(-2)],
+        ]);
+
+        let b = matrix![[2, 1, 0], [1, 3, 1], [0, 1, 4]];
+        let top: ExteriorPower<Rational, 3, 3> = ExteriorPower::from_vectors(&[tuple![1, 0, 0], tuple![0, 1, 0], tuple![0, 0, 1]]);
+        let columns = [tuple![2, 1, 0], tuple![1, 3, 1], tuple![0, 1, 4]];
+        assert_eq!(ExteriorPowerMap::<_, 3>(b).apply(&top), top * determinant(&columns));
+    }
+}
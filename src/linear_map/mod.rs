@@ -0,0 +1,223 @@
+mod derivative;
+mod representation;
+mod transpose;
+mod tensor;
+mod exterior_power;
+mod complexify;
+
+pub use derivative::Derivative;
+pub use representation::{MatrixMap, change_of_coordinate_matrix};
+pub use transpose::Transpose;
+pub use tensor::TensorMap;
+pub use exterior_power::ExteriorPowerMap;
+pub use complexify::{ComplexifiedMap, RealifiedMap};
+
+use crate::field::Field;
+use crate::vector_space::{FiniteDimensional, Matrix, Tuple, VectorSpace};
+use crate::vector_space::echelon::{row_reduce, null_space};
+use std::marker::PhantomData;
+
+/// A trait for functions T: V -> W between vector spaces over F which are linear.
+/// T(x + y) = T(x) + T(y) and T(cx) = cT(x) must hold for all vectors x, y and scalars c.
+pub trait LinearMap<F: Field, V, W> {
+    /// Returns the image T(v) of the given vector.
+    fn apply(&self, v: &V) -> W;
+
+    /// Returns the composition TU, which applies `inner` first and then this map.
+    fn compose<U, S: LinearMap<F, U, V>>(self, inner: S) -> Composition<Self, S, V>
+    where Self: Sized {
+        Composition {
+            outer: self,
+            inner,
+            _middle: PhantomData
+        }
+    }
+
+    /// Returns the pointwise sum T + U. This is named apart from `Add::add`, which matrices also implement.
+    fn sum_map<S: LinearMap<F, V, W>>(self, other: S) -> Sum<Self, S>
+    where Self: Sized {
+        Sum(self, other)
+    }
+
+    /// Returns the pointwise scalar multiple cT.
+    fn scaled(self, c: F) -> Scaled<Self, F>
+    where Self: Sized {
+        Scaled(self, c)
+    }
+
+    /// Returns the standard matrix of the map as a list of rows,
+    /// whose j'th column holds the entries of T(e_j).
+    fn standard_rows(&self) -> Vec<Vec<F>>
+    where V: FiniteDimensional<F>, W: FiniteDimensional<F> {
+        let columns: Vec<Vec<F>> = V::standard_basis().iter().map(|e| self.apply(e).entries()).collect();
+        (0..W::dim()).map(|i| columns.iter().map(|c| c[i]).collect()).collect()
+    }
+
+    /// Returns the matrix representation [T]_β^γ relative to ordered bases β of V and γ of W,
+    /// whose j'th column is [T(v_j)]_γ.
+    fn matrix_relative_to<const M: usize, const N: usize>(&self, beta: &[V; N], gamma: &[W; M]) -> Matrix<F, M, N>
+    where V: FiniteDimensional<F>, W: FiniteDimensional<F> {
+        let mut matrix = <Matrix<F, M, N> as VectorSpace<F>>::zero();
+        for j in 0..N {
+            let column = self.apply(&beta[j]).coordinates(gamma);
+            for i in 0..M {
+                matrix[(i, j)] = column[i];
+            }
+        }
+        matrix
+    }
+
+    /// Returns a basis for the null space N(T) = {x in V : T(x) = 0}.
+    fn kernel(&self) -> Vec<V>
+    where V: FiniteDimensional<F>, W: FiniteDimensional<F> {
+        let mut rows = self.standard_rows();
+        let pivots = row_reduce(&mut rows);
+        null_space(&rows, &pivots, V::dim()).iter().map(|x| V::from_entries(x)).collect()
+    }
+
+    /// Returns a basis for the range R(T) = {T(x) : x in V}, made up of images of standard basis vectors.
+    fn image(&self) -> Vec<W>
+    where V: FiniteDimensional<F>, W: FiniteDimensional<F> {
+        let mut rows = self.standard_rows();
+        let basis = V::standard_basis();
+        row_reduce(&mut rows).iter().map(|&j| self.apply(&basis[j])).collect()
+    }
+
+    /// Returns the rank of the map, which is the dimension of its range.
+    fn rank(&self) -> usize
+    where V: FiniteDimensional<F>, W: FiniteDimensional<F> {
+        self.image().len()
+    }
+
+    /// Returns the nullity of the map, which is the dimension of its null space.
+    fn nullity(&self) -> usize
+    where V: FiniteDimensional<F>, W: FiniteDimensional<F> {
+        self.kernel().len()
+    }
+
+    /// Returns whether the map is one-to-one, which is the case exactly when N(T) = {0}.
+    fn is_injective(&self) -> bool
+    where V: FiniteDimensional<F>, W: FiniteDimensional<F> {
+        self.nullity() == 0
+    }
+
+    /// Returns whether the map is onto, which is the case exactly when R(T) = W.
+    fn is_surjective(&self) -> bool
+    where V: FiniteDimensional<F>, W: FiniteDimensional<F> {
+        self.rank() == W::dim()
+    }
+
+    /// Checks the dimension theorem (2.3), nullity(T) + rank(T) = dim(V),
+    /// with the null space and range computed independently of each other.
+    fn satisfies_rank_nullity(&self) -> bool
+    where V: FiniteDimensional<F>, W: FiniteDimensional<F> {
+        self.nullity() + self.rank() == V::dim()
+    }
+}
+
+/// A linear map given by a closure, which the caller is trusted to have made linear.
+pub struct FnMap<F: Field, V, W>(Box<dyn Fn(&V) -> W>, PhantomData<F>);
+
+impl<F: Field, V, W> FnMap<F, V, W> {
+    pub fn new(f: impl Fn(&V) -> W + 'static) -> Self {
+        Self(Box::new(f), PhantomData)
+    }
+}
+
+impl<F: Field, V, W> LinearMap<F, V, W> for FnMap<F, V, W> {
+    fn apply(&self, v: &V) -> W {
+        (*self.0)(v)
+    }
+}
+
+/// The composition TU of two linear maps, as returned by `LinearMap::compose`.
+pub struct Composition<T, S, V> {
+    outer: T,
+    inner: S,
+    _middle: PhantomData<fn(V) -> V>,
+}
+
+impl<F: Field, U, V, W, T: LinearMap<F, V, W>, S: LinearMap<F, U, V>> LinearMap<F, U, W> for Composition<T, S, V> {
+    fn apply(&self, u: &U) -> W {
+        self.outer.apply(&self.inner.apply(u))
+    }
+}
+
+/// The pointwise sum T + U of two linear maps, as returned by `LinearMap::sum_map`.
+pub struct Sum<T, S>(T, S);
+
+impl<F: Field, V, W: VectorSpace<F>, T: LinearMap<F, V, W>, S: LinearMap<F, V, W>> LinearMap<F, V, W> for Sum<T, S> {
+    fn apply(&self, v: &V) -> W {
+        self.0.apply(v) + self.1.apply(v)
+    }
+}
+
+/// The pointwise scalar multiple cT of a linear map, as returned by `LinearMap::scaled`.
+pub struct Scaled<T, F>(T, F);
+
+impl<F: Field, V, W: VectorSpace<F>, T: LinearMap<F, V, W>> LinearMap<F, V, W> for Scaled<T, F> {
+    fn apply(&self, v: &V) -> W {
+        self.0.apply(v) * self.1
+    }
+}
+
+// Left-multiplication transformation L_A: F^N -> F^M.
+impl<F: Field, const M: usize, const N: usize> LinearMap<F, Tuple<F, N>, Tuple<F, M>> for Matrix<F, M, N> {
+    fn apply(&self, v: &Tuple<F, N>) -> Tuple<F, M> {
+        self * v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinearMap, FnMap, Derivative};
+    use crate::field::{IntMod2, Rational};
+    use crate::vector_space::{BoundedPolynom, Polynom, Tuple};
+    use crate::{tuple, matrix, polynom};
+
+    #[test]
+    fn linear_map_kernel_and_image() {
+        let t = FnMap::new(|a: &Tuple<Rational, 3>| tuple![a[0] - a[1], a[2] * 2]);
+        assert_eq!(t.kernel(), vec![tuple![1, 1, 0]]);
+        assert_eq!(t.image(), vec![tuple![1, 0], tuple![0, 2]]);
+        assert!(t.is_surjective() && !t.is_injective());
+        assert!(t.satisfies_rank_nullity());
+    }
+
+    #[test]
+    fn linear_map_matrix() {
+        let a = matrix![[1, 2, 1], [2, 4, 2]];
+        assert_eq!(a.apply(&tuple![1, 1, 1]), tuple![4, 8]);
+        assert_eq!(a.rank(), 1);
+        assert_eq!(a.nullity(), 2);
+        assert!(a.satisfies_rank_nullity());
+
+        let (o, i) = (IntMod2::Zero, IntMod2::One);
+        let b = matrix![[i, i], [i, i]];
+        assert_eq!(b.kernel(), vec![tuple![i, i]]);
+        assert_eq!(b.apply(&tuple![i, o]), tuple![i, i]);
+    }
+
+    #[test]
+    fn linear_map_combinations() {
+        let a = matrix![[1, 2], [3, 4]];
+        let b = matrix![[0, 1], [1, 0]];
+        let x = tuple![1, -1];
+        assert_eq!(a.compose(b).apply(&x), tuple![1, 1]);
+        assert_eq!(a.sum_map(b).apply(&x), tuple![-2, 0]);
+        assert_eq!((a + b).apply(&x), a.sum_map(b).apply(&x));
+        assert_eq!(a.scaled(Rational::from(3)).apply(&x), tuple![-3, -3]);
+    }
+
+    #[test]
+    fn linear_map_derivative() {
+        let d = Derivative::<Polynom<Rational>>::new();
+        assert_eq!(d.apply(&polynom![1, 2, 3, 4]), polynom![2, 6, 12]);
+
+        let d = Derivative::<BoundedPolynom<Rational, 3>>::new();
+        assert_eq!(d.kernel(), vec![BoundedPolynom::new(polynom![1]).unwrap()]);
+        assert_eq!(d.rank(), 3);
+        assert!(!d.is_surjective());
+        assert!(d.satisfies_rank_nullity());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::field::Field;
+use crate::vector_space::{FiniteDimensional, Matrix};
+use super::LinearMap;
+use std::marker::PhantomData;
+
+/// The linear map T: V -> W with [T]_β^γ equal to a given matrix, for ordered bases β of V and γ of W.
+/// T(x) is the vector of W whose γ-coordinates are A[x]_β.
+pub struct MatrixMap<F: Field, V, W, const M: usize, const N: usize> {
+    matrix: Matrix<F, M, N>,
+    beta: [V; N],
+    gamma: [W; M],
+}
+
+impl<F: Field, V, W, const M: usize, const N: usize> MatrixMap<F, V, W, M, N> {
+    /// Creates the map with the given matrix representation. Assumes that β and γ are bases.
+    pub fn new(matrix: Matrix<F, M, N>, beta: [V; N], gamma: [W; M]) -> Self {
+        Self {
+            matrix,
+            beta,
+            gamma
+        }
+    }
+}
+
+impl<F: Field, V: FiniteDimensional<F>, W: FiniteDimensional<F>, const M: usize, const N: usize> LinearMap<F, V, W> for MatrixMap<F, V, W, M, N> {
+    fn apply(&self, x: &V) -> W {
+        W::from_coordinates(&self.matrix.apply(&x.coordinates(&self.beta)), &self.gamma)
+    }
+}
+
+/// Returns the change of coordinate matrix Q = [I]_β'^β, which changes β' coordinates into β coordinates.
+/// Its j'th column is [v'_j]_β, and [x]_β = Q[x]_β' holds for every x (2.22).
+pub fn change_of_coordinate_matrix<F: Field, V: FiniteDimensional<F>, const N: usize>(from: &[V; N], to: &[V; N]) -> Matrix<F, N, N> {
+    let identity = IdentityMap(PhantomData);
+    identity.matrix_relative_to(from, to)
+}
+
+/// The identity transformation I_V.
+struct IdentityMap<V>(PhantomData<V>);
+
+impl<F: Field, V: FiniteDimensional<F>> LinearMap<F, V, V> for IdentityMap<V> {
+    fn apply(&self, x: &V) -> V {
+        x.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MatrixMap, change_of_coordinate_matrix};
+    use crate::field::Rational;
+    use crate::linear_map::{LinearMap, FnMap, Derivative};
+    use crate::vector_space::{BoundedPolynom, FiniteDimensional, Tuple};
+    use crate::{tuple, matrix, polynom};
+
+    #[test]
+    fn representation_matrix_relative_to() {
+        // 2.2 Example 3
+        let t = FnMap::new(|a: &Tuple<Rational, 2>| tuple![a[0] + a[1] * 3, Rational::from(0), a[0] * 2 - a[1] * 4]);
+        let beta = [tuple![1, 0], tuple![0, 1]];
+        let gamma = [tuple![1, 0, 0], tuple![0, 1, 0], tuple![0, 0, 1]];
+        assert_eq!(t.matrix_relative_to(&beta, &gamma), matrix![[1, 3], [0, 0], [2, -4]]);
+        let gamma = [tuple![0, 0, 1], tuple![0, 1, 0], tuple![1, 0, 0]];
+        assert_eq!(t.matrix_relative_to(&beta, &gamma), matrix![[2, -4], [0, 0], [1, 3]]);
+
+        let d = Derivative::<BoundedPolynom<Rational, 3>>::new();
+        let beta = BoundedPolynom::standard_basis();
+        let beta = [beta[0].clone(), beta[1].clone(), beta[2].clone(), beta[3].clone()];
+        assert_eq!(d.matrix_relative_to(&beta, &beta), matrix![[0, 1, 0, 0], [0, 0, 2, 0], [0, 0, 0, 3], [0, 0, 0, 0]]);
+    }
+
+    #[test]
+    fn representation_matrix_map() {
+        let beta = [BoundedPolynom::new(polynom![1]).unwrap(), BoundedPolynom::new(polynom![1, 1]).unwrap()];
+        let gamma = [tuple![1, 1], tuple![1, -1]];
+        let a = matrix![[1, 0], [0, 2]];
+        let t: MatrixMap<Rational, BoundedPolynom<Rational, 1>, Tuple<Rational, 2>, 2, 2> = MatrixMap::new(a, beta.clone(), gamma.clone());
+        assert_eq!(t.apply(&BoundedPolynom::new(polynom![0, 1]).unwrap()), tuple![1, -3]);
+        assert_eq!(t.matrix_relative_to(&beta, &gamma), a);
+    }
+
+    #[test]
+    fn representation_change_of_coordinates() {
+        // 2.5 Example 1
+        let beta = [tuple![1, 1], tuple![1, -1]];
+        let beta_prime = [tuple![2, 4], tuple![3, 1]];
+        let q = change_of_coordinate_matrix(&beta_prime, &beta);
+        assert_eq!(q, matrix![[3, 2], [-1, 1]]);
+        assert_eq!(q * matrix![[1], [1]], matrix![[5], [0]]);
+
+        // 2.5 Example 2: T(a, b) = (2a + b, a - 3b)
+        let t = matrix![[2, 1], [1, -3]];
+        let standard = [tuple![1, 0], tuple![0, 1]];
+        let beta_prime = [tuple![1, 1], tuple![1, 2]];
+        let q = change_of_coordinate_matrix(&beta_prime, &standard);
+        assert_eq!(t.similarity_transform(&q).unwrap(), matrix![[8, 13], [-5, -9]]);
+        assert_eq!(t.matrix_relative_to(&beta_prime, &beta_prime), matrix![[8, 13], [-5, -9]]);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::field::Field;
+use crate::vector_space::{FiniteDimensional, TensorProduct, VectorSpace};
+use super::LinearMap;
+
+/// The tensor product T ⊗ U: V ⊗ W -> V' ⊗ W' of linear maps T: V -> V' and U: W -> W',
+/// which is the unique linear map with (T ⊗ U)(v ⊗ w) = T(v) ⊗ U(w).
+/// Relative to the standard bases its matrix is the Kronecker product of the standard matrices of T and U.
+pub struct TensorMap<T, U>(pub T, pub U);
+
+impl<F, V1, V2, W1, W2, T, U> LinearMap<F, TensorProduct<F, V1, W1>, TensorProduct<F, V2, W2>> for TensorMap<T, U>
+where
+    F: Field,
+    V1: FiniteDimensional<F>,
+    V2: FiniteDimensional<F>,
+    W1: FiniteDimensional<F>,
+    W2: FiniteDimensional<F>,
+    T: LinearMap<F, V1, V2>,
+    U: LinearMap<F, W1, W2>,
+{
+    fn apply(&self, x: &TensorProduct<F, V1, W1>) -> TensorProduct<F, V2, W2> {
+        let left: Vec<V2> = V1::standard_basis().iter().map(|e| self.0.apply(e)).collect();
+        let right: Vec<W2> = W1::standard_basis().iter().map(|f| self.1.apply(f)).collect();
+        let mut out = <TensorProduct<F, V2, W2> as VectorSpace<F>>::zero();
+        for (i, v) in left.iter().enumerate() {
+            for (j, w) in right.iter().enumerate() {
+                out += TensorProduct::simple(v, w) * x.coefficient(i, j);
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TensorMap;
+    use crate::field::Rational;
+    use crate::linear_map::{LinearMap, FnMap};
+    use crate::vector_space::{Matrix, TensorProduct, Tuple};
+    use crate::{tuple, matrix};
+
+    #[test]
+    fn tensor_map_simple() {
+        let a = matrix![[1, 2], [0, 1]];
+        let u = FnMap::new(|p: &Tuple<Rational, 3>| tuple![p[0] + p[2], p[1]]);
+        let t = TensorMap(a, u);
+        let v = tuple![1, 1];
+        let w = tuple![2, 0, 1];
+        assert_eq!(t.apply(&TensorProduct::simple(&v, &w)), TensorProduct::simple(&a.apply(&v), &tuple![3, 0]));
+    }
+
+    #[test]
+    fn tensor_map_kronecker() {
+        let a = matrix![[1, 2], [3, 4]];
+        let b = matrix![[2, 0], [1, -1]];
+        let k: Matrix<Rational, 4, 4> = a.kronecker(&b);
+        let rows: Vec<Vec<Rational>> = (0..4).map(|i| (0..4).map(|j| k[(i, j)]).collect()).collect();
+        assert_eq!(TensorMap(a, b).standard_rows(), rows);
+    }
+}
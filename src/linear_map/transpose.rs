@@ -0,0 +1,43 @@
+use crate::field::Field;
+use crate::vector_space::{Dual, FiniteDimensional};
+use super::LinearMap;
+
+/// The transpose Tᵗ: W* -> V* of a linear map T: V -> W, defined by Tᵗ(g) = gT.
+/// Relative to dual bases, [Tᵗ]_γ*^β* is the transpose of [T]_β^γ (2.25).
+pub struct Transpose<T>(pub T);
+
+impl<F: Field, V: FiniteDimensional<F>, W: FiniteDimensional<F>, T: LinearMap<F, V, W>> LinearMap<F, Dual<F, W>, Dual<F, V>> for Transpose<T> {
+    fn apply(&self, g: &Dual<F, W>) -> Dual<F, V> {
+        Dual::from_fn(|x| g.apply(&self.0.apply(x)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Transpose;
+    use crate::field::Rational;
+    use crate::linear_map::{LinearMap, FnMap};
+    use crate::vector_space::{Dual, Tuple};
+    use crate::{tuple, matrix};
+
+    #[test]
+    fn transpose_matrix() {
+        // 2.6 Example 5: T(p) = (p(0), p(2)) on P_1(R), here written in coordinates.
+        let t = FnMap::new(|p: &Tuple<Rational, 2>| tuple![p[0], p[0] + p[1] * 2]);
+        let beta = [tuple![1, 0], tuple![0, 1]];
+        let gamma = [tuple![1, 0], tuple![0, 1]];
+        assert_eq!(t.matrix_relative_to(&beta, &gamma), matrix![[1, 0], [1, 2]]);
+
+        let beta_star = Dual::dual_basis(&beta);
+        let gamma_star = Dual::dual_basis(&gamma);
+        assert_eq!(Transpose(t).matrix_relative_to(&gamma_star, &beta_star), matrix![[1, 1], [0, 2]]);
+    }
+
+    #[test]
+    fn transpose_apply() {
+        let t = matrix![[1, 2, 0], [0, 1, 1]];
+        let g = Dual::from_fn(|y: &Tuple<Rational, 2>| y[0] - y[1]);
+        let x = tuple![1, 1, 1];
+        assert_eq!(Transpose(t).apply(&g).apply(&x), g.apply(&t.apply(&x)));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::field::{ConjugateField, Real};
+use crate::vector_space::{Tuple, Matrix, VectorSpace};
+use std::ops::Sub;
+
+/// A trait for vector spaces with a norm ||x||.
+/// The norm must satisfy ||x|| ≥ 0 with equality only for x = 0, ||cx|| = |c| ||x||,
+/// and the triangle inequality ||x + y|| ≤ ||x|| + ||y||.
+/// For inner product spaces this must be the norm induced by the inner product.
+pub trait Normed: Sized + Clone + Sub<Self, Output=Self> {
+    /// Returns the norm ||x||.
+    fn norm(&self) -> Real;
+
+    /// Returns the distance ||x - y|| between two vectors.
+    fn distance(&self, other: &Self) -> Real {
+        (self.clone() - other.clone()).norm()
+    }
+}
+
+// The default norm on F^N is the Euclidean 2-norm.
+impl<F: ConjugateField, const N: usize> Normed for Tuple<F, N> {
+    fn norm(&self) -> Real {
+        self.norm_2()
+    }
+}
+
+impl<F: ConjugateField, const N: usize> Tuple<F, N> {
+    /// Returns the 1-norm Σ |x_i|.
+    pub fn norm_1(&self) -> Real {
+        (0..N).map(|i| self[i].modulus()).sum()
+    }
+
+    /// Returns the Euclidean 2-norm sqrt(Σ |x_i|²).
+    pub fn norm_2(&self) -> Real {
+        (0..N).map(|i| self[i].modulus().powi(2)).sum::<Real>().sqrt()
+    }
+
+    /// Returns the ∞-norm max |x_i|.
+    pub fn norm_inf(&self) -> Real {
+        (0..N).map(|i| self[i].modulus()).fold(0.0, Real::max)
+    }
+
+    /// Returns the p-norm (Σ |x_i|^p)^(1/p). An infinite p gives the ∞-norm.
+    /// Panics if p < 1, since the triangle inequality fails there.
+    pub fn norm_p(&self, p: Real) -> Real {
+        assert!(p >= 1.0, "the p-norm requires p ≥ 1, not {}", p);
+        if p.is_infinite() {
+            return self.norm_inf();
+        }
+        (0..N).map(|i| self[i].modulus().powf(p)).sum::<Real>().powf(1.0 / p)
+    }
+
+    /// Returns the distance ||x - y||_p in the p-norm.
+    pub fn distance_p(&self, other: &Self, p: Real) -> Real {
+        (self.clone() - other).norm_p(p)
+    }
+}
+
+// The default norm on matrices is the Frobenius norm, which is induced by the Frobenius inner product.
+impl<F: ConjugateField, const M: usize, const N: usize> Normed for Matrix<F, M, N> {
+    fn norm(&self) -> Real {
+        self.frobenius_norm()
+    }
+}
+
+impl<F: ConjugateField, const M: usize, const N: usize> Matrix<F, M, N> {
+    /// Returns the Frobenius norm sqrt(Σ |A_ij|²).
+    pub fn frobenius_norm(&self) -> Real {
+        let mut accum = 0.0;
+        for i in 0..M {
+            for j in 0..N {
+                accum += self[(i, j)].modulus().powi(2);
+            }
+        }
+        accum.sqrt()
+    }
+
+    /// Returns the norm induced by the vector 1-norm, which is the maximum absolute column sum.
+    pub fn norm_1(&self) -> Real {
+        (0..N).map(|j| (0..M).map(|i| self[(i, j)].modulus()).sum())
+            .fold(0.0, Real::max)
+    }
+
+    /// Returns the norm induced by the vector ∞-norm, which is the maximum absolute row sum.
+    pub fn norm_inf(&self) -> Real {
+        (0..M).map(|i| (0..N).map(|j| self[(i, j)].modulus()).sum())
+            .fold(0.0, Real::max)
+    }
+}
+
+impl<F: ConjugateField + From<Real>, const M: usize, const N: usize> Matrix<F, M, N> {
+    /// Returns the spectral norm, which is the norm induced by the vector 2-norm and equals the
+    /// largest singular value of A. It is approximated by power iteration on A*A started from Σe_i,
+    /// until successive estimates agree to a relative tolerance. Since the largest eigenvalue of A*A
+    /// is at least each diagonal entry (A*A)_kk, an estimate below one of these means the start was
+    /// orthogonal to the dominant eigenvector, and the iteration is restarted from e_k.
+    pub fn spectral_norm(&self) -> Real {
+        let b = self.adjoint() * *self;
+        let mut start = <Tuple<F, N> as VectorSpace<F>>::zero();
+        for i in 0..N {
+            start[i] = F::one();
+        }
+        let mut largest = power_iteration(&b, start);
+        let diagonal = |k: usize| b[(k, k)].real_part();
+        if let Some(k) = (0..N).reduce(|best, k| if diagonal(k) > diagonal(best) { k } else { best }) {
+            if largest < diagonal(k) * (1.0 - SPECTRAL_TOLERANCE) {
+                let mut e = <Tuple<F, N> as VectorSpace<F>>::zero();
+                e[k] = F::one();
+                largest = largest.max(power_iteration(&b, e));
+            }
+        }
+        largest.sqrt()
+    }
+}
+
+/// The relative change between successive eigenvalue estimates at which power iteration stops.
+const SPECTRAL_TOLERANCE: Real = 1e-12;
+
+/// Returns the eigenvalue of B found by power iteration from x, stopping once successive
+/// estimates ||Bx|| / ||x|| agree to the tolerance, or after a fixed number of steps.
+fn power_iteration<F: ConjugateField + From<Real>, const N: usize>(b: &Matrix<F, N, N>, mut x: Tuple<F, N>) -> Real {
+    const MAX_ITERATIONS: usize = 10_000;
+    let mut eigenvalue: Real = 0.0;
+    let norm = x.norm_2();
+    if norm == 0.0 {
+        return 0.0;
+    }
+    x /= F::from(norm);
+    for _ in 0..MAX_ITERATIONS {
+        let y = b * &x;
+        let next = y.norm_2();
+        if next == 0.0 {
+            return 0.0;
+        }
+        x = y / F::from(next);
+        let converged = (next - eigenvalue).abs() <= SPECTRAL_TOLERANCE * next;
+        eigenvalue = next;
+        if converged {
+            break;
+        }
+    }
+    eigenvalue
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Normed;
+    use crate::{tuple, matrix, cmplx};
+
+    #[test]
+    fn norm_tuples() {
+        let x = tuple![1.0, -2.0, 2.0];
+        assert_eq!(x.norm_1(), 5.0);
+        assert_eq!(x.norm_2(), 3.0);
+        assert_eq!(x.norm(), 3.0);
+        assert_eq!(x.norm_inf(), 2.0);
+        assert_eq!(x.norm_p(3.0), 17.0f64.powf(1.0 / 3.0));
+        assert_eq!(x.norm_p(f64::INFINITY), 2.0);
+        assert_eq!(tuple![cmplx!(3, 4), cmplx!(0)].norm_1(), 5.0);
+        assert_eq!(tuple![1, 2].norm_1(), 3.0);
+    }
+
+    #[test]
+    fn norm_distance() {
+        let x = tuple![1.0, 1.0];
+        let y = tuple![4.0, 5.0];
+        assert_eq!(x.distance(&y), 5.0);
+        assert_eq!(x.distance_p(&y, 1.0), 7.0);
+        assert_eq!(x.distance_p(&y, f64::INFINITY), 4.0);
+    }
+
+    #[test]
+    fn norm_matrices() {
+        let a = matrix![[1.0, -2.0], [3.0, 4.0]];
+        assert_eq!(a.norm_1(), 6.0);
+        assert_eq!(a.norm_inf(), 7.0);
+        assert_eq!(a.frobenius_norm(), 30.0f64.sqrt());
+        assert_eq!(a.norm(), a.frobenius_norm());
+
+        let b = matrix![[3.0, 0.0], [4.0, 5.0]];
+        assert!((b.spectral_norm() - 45.0f64.sqrt()).abs() < 1e-10);
+        let c = matrix![[cmplx!(0, 2), cmplx!(0)], [cmplx!(0), cmplx!(1)]];
+        assert!((c.spectral_norm() - 2.0).abs() < 1e-10);
+
+        // Σe_i is an eigenvector of A*A for its smaller eigenvalue, or lies in its null space.
+        assert!((matrix![[2.0, -1.0], [-1.0, 2.0]].spectral_norm() - 3.0).abs() < 1e-10);
+        assert!((matrix![[1.0, -1.0]].spectral_norm() - 2.0f64.sqrt()).abs() < 1e-10);
+        assert_eq!(matrix![[0.0, 0.0], [0.0, 0.0]].spectral_norm(), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn norm_p_below_one() {
+        tuple![1.0, 1.0].norm_p(0.5);
+    }
+}
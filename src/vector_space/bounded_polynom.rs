@@ -0,0 +1,154 @@
+use crate::field::Field;
+use super::{VectorSpace, Entries, FiniteDimensional, Polynom};
+use std::ops::{Add, Mul, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssign, Neg};
+use std::fmt;
+
+#[derive(PartialEq, Debug, Clone)]
+/// A type representing a polynomial of degree at most N, making up the space P_N(F).
+/// Unlike `Polynom`, this space is finite-dimensional, with standard ordered basis {1, x, ..., x^N}.
+pub struct BoundedPolynom<F: Field, const N: usize>(Polynom<F>);
+
+impl<F: Field, const N: usize> BoundedPolynom<F, N> {
+    /// Wraps the given polynomial, or returns `None` if its degree exceeds N.
+    pub fn new(p: Polynom<F>) -> Option<Self> {
+        match p.degree() {
+            Some(d) if d > N => None,
+            _ => Some(Self(p)),
+        }
+    }
+
+    /// Returns the underlying polynomial.
+    pub fn polynom(&self) -> &Polynom<F> {
+        &self.0
+    }
+}
+
+impl<F: Field, const N: usize> From<BoundedPolynom<F, N>> for Polynom<F> {
+    fn from(p: BoundedPolynom<F, N>) -> Self {
+        p.0
+    }
+}
+
+// Operator implementations, which never raise the degree and so forward to `Polynom`.
+impl<F: Field, const N: usize> Neg for BoundedPolynom<F, N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+impl<F: Field, const N: usize> Add<&Self> for BoundedPolynom<F, N> {
+    type Output = Self;
+    fn add(self, rhs: &Self) -> Self {
+        Self(self.0 + &rhs.0)
+    }
+}
+impl<F: Field, const N: usize> Add<Self> for BoundedPolynom<F, N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+impl<F: Field, const N: usize> Sub<&Self> for BoundedPolynom<F, N> {
+    type Output = Self;
+    fn sub(self, rhs: &Self) -> Self {
+        Self(self.0 - &rhs.0)
+    }
+}
+impl<F: Field, const N: usize> Sub<Self> for BoundedPolynom<F, N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - &rhs.0)
+    }
+}
+impl<F: Field, const N: usize> AddAssign<&Self> for BoundedPolynom<F, N> {
+    fn add_assign(&mut self, rhs: &Self) {
+        self.0 += &rhs.0;
+    }
+}
+impl<F: Field, const N: usize> AddAssign<Self> for BoundedPolynom<F, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+impl<F: Field, const N: usize> SubAssign<&Self> for BoundedPolynom<F, N> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.0 -= &rhs.0;
+    }
+}
+impl<F: Field, const N: usize> SubAssign<Self> for BoundedPolynom<F, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= &rhs.0;
+    }
+}
+impl<T: Into<F>, F: Field, const N: usize> Mul<T> for BoundedPolynom<F, N> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        Self(self.0 * rhs)
+    }
+}
+impl<T: Into<F>, F: Field, const N: usize> Div<T> for BoundedPolynom<F, N> {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self {
+        Self(self.0 / rhs)
+    }
+}
+impl<T: Into<F>, F: Field, const N: usize> MulAssign<T> for BoundedPolynom<F, N> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.0 *= rhs;
+    }
+}
+impl<T: Into<F>, F: Field, const N: usize> DivAssign<T> for BoundedPolynom<F, N> {
+    fn div_assign(&mut self, rhs: T) {
+        self.0 /= rhs;
+    }
+}
+
+impl<F: Field, const N: usize> fmt::Display for BoundedPolynom<F, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// Entries are the N+1 coefficients, padded with zeros.
+impl<F: Field, const N: usize> Entries<F> for BoundedPolynom<F, N> {
+    fn entries(&self) -> Vec<F> {
+        let mut entries = self.0.entries();
+        entries.resize(N + 1, F::zero());
+        entries
+    }
+}
+
+impl<F: Field, const N: usize> VectorSpace<F> for BoundedPolynom<F, N> {
+    fn zero() -> Self {
+        Self(<Polynom<F> as VectorSpace<F>>::zero())
+    }
+}
+
+impl<F: Field, const N: usize> FiniteDimensional<F> for BoundedPolynom<F, N> {
+    fn dim() -> usize {
+        N + 1
+    }
+
+    fn from_entries(entries: &[F]) -> Self {
+        Self(Polynom::from(entries.to_vec()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoundedPolynom;
+    use crate::field::Rational;
+    use crate::polynom;
+
+    #[test]
+    fn bounded_polynom_sub() {
+        let f = BoundedPolynom::<Rational, 2>::new(polynom![1]).unwrap();
+        let g = BoundedPolynom::<Rational, 2>::new(polynom![0, 1]).unwrap();
+        let expected = BoundedPolynom::new(polynom![1, -1]).unwrap();
+        assert_eq!(f.clone() - g.clone(), expected);
+        assert_eq!(f.clone() - &g, expected);
+        let mut h = f;
+        h -= g;
+        assert_eq!(h, expected);
+    }
+}
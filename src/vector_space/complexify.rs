@@ -0,0 +1,311 @@
+use crate::field::{Complex, Real};
+use super::{VectorSpace, Entries, FiniteDimensional};
+use std::ops::{Add, Mul, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssign, Neg};
+use std::fmt;
+
+/// A type representing a vector u + iv of the complexification V_C of a real vector space V.
+/// V_C is a complex vector space under (a + ib)(u + iv) = (au - bv) + i(bu + av),
+/// and the standard basis of V is also a basis of V_C, so dim_C(V_C) = dim_R(V).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Complexify<V>(V, V);
+
+impl<V: VectorSpace<Real> + Clone> Complexify<V> {
+    /// Creates the vector u + iv.
+    pub fn new(u: V, v: V) -> Self {
+        Self(u, v)
+    }
+
+    /// Returns the real part u of u + iv.
+    pub fn real(&self) -> &V {
+        &self.0
+    }
+
+    /// Returns the imaginary part v of u + iv.
+    pub fn imaginary(&self) -> &V {
+        &self.1
+    }
+
+    /// Returns the conjugate u - iv. Conjugation is additive but conjugate-linear, and its fixed
+    /// points are the vectors of V.
+    pub fn conjugate(&self) -> Self {
+        Self(self.0.clone(), -self.1.clone())
+    }
+}
+
+// V embeds in V_C as the vectors u + i0.
+impl<V: VectorSpace<Real>> From<V> for Complexify<V> {
+    fn from(u: V) -> Self {
+        Self(u, V::zero())
+    }
+}
+
+// Operator implementations for complexified vectors with complexified vectors.
+impl<V: VectorSpace<Real>> Neg for Complexify<V> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1)
+    }
+}
+impl<V: VectorSpace<Real> + Clone> Add<&Self> for Complexify<V> {
+    type Output = Self;
+    fn add(mut self, rhs: &Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl<V: VectorSpace<Real>> Add<Self> for Complexify<V> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl<V: VectorSpace<Real> + Clone> Sub<&Self> for Complexify<V> {
+    type Output = Self;
+    fn sub(mut self, rhs: &Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl<V: VectorSpace<Real>> Sub<Self> for Complexify<V> {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl<V: VectorSpace<Real> + Clone> AddAssign<&Self> for Complexify<V> {
+    fn add_assign(&mut self, rhs: &Self) {
+        self.0 += rhs.0.clone();
+        self.1 += rhs.1.clone();
+    }
+}
+impl<V: VectorSpace<Real>> AddAssign<Self> for Complexify<V> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+        self.1 += rhs.1;
+    }
+}
+impl<V: VectorSpace<Real> + Clone> SubAssign<&Self> for Complexify<V> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.0 -= rhs.0.clone();
+        self.1 -= rhs.1.clone();
+    }
+}
+impl<V: VectorSpace<Real>> SubAssign<Self> for Complexify<V> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+        self.1 -= rhs.1;
+    }
+}
+
+// Operator implementations for complexified vectors with complex scalars.
+impl<T: Into<Complex>, V: VectorSpace<Real> + Clone> Mul<T> for Complexify<V> {
+    type Output = Self;
+    fn mul(mut self, rhs: T) -> Self {
+        self *= rhs;
+        self
+    }
+}
+impl<T: Into<Complex>, V: VectorSpace<Real> + Clone> Div<T> for Complexify<V> {
+    type Output = Self;
+    fn div(mut self, rhs: T) -> Self {
+        self /= rhs;
+        self
+    }
+}
+impl<T: Into<Complex>, V: VectorSpace<Real> + Clone> MulAssign<T> for Complexify<V> {
+    fn mul_assign(&mut self, rhs: T) {
+        let c = rhs.into();
+        let (u, v) = (self.0.clone(), self.1.clone());
+        self.0 = u.clone() * c.re - v.clone() * c.im;
+        self.1 = u * c.im + v * c.re;
+    }
+}
+impl<T: Into<Complex>, V: VectorSpace<Real> + Clone> DivAssign<T> for Complexify<V> {
+    fn div_assign(&mut self, rhs: T) {
+        *self *= Complex::from(1.0) / rhs.into();
+    }
+}
+
+impl<V: fmt::Display> fmt::Display for Complexify<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}+i{}", self.0, self.1)
+    }
+}
+
+impl<V: Entries<Real>> Entries<Complex> for Complexify<V> {
+    fn entries(&self) -> Vec<Complex> {
+        let (u, v) = (self.0.entries(), self.1.entries());
+        (0..u.len().max(v.len()))
+            .map(|i| Complex::new(u.get(i).copied().unwrap_or(0.0), v.get(i).copied().unwrap_or(0.0)))
+            .collect()
+    }
+}
+
+impl<V: VectorSpace<Real> + Clone> VectorSpace<Complex> for Complexify<V> {
+    fn zero() -> Self {
+        Self(V::zero(), V::zero())
+    }
+}
+
+impl<V: FiniteDimensional<Real>> FiniteDimensional<Complex> for Complexify<V> {
+    fn dim() -> usize {
+        V::dim()
+    }
+
+    fn from_entries(entries: &[Complex]) -> Self {
+        let u: Vec<Real> = entries.iter().map(|z| z.re).collect();
+        let v: Vec<Real> = entries.iter().map(|z| z.im).collect();
+        Self(V::from_entries(&u), V::from_entries(&v))
+    }
+}
+
+/// A type viewing a complex vector space V as the real vector space V_R, by restricting scalars to the reals.
+/// If {e_1, ..., e_n} is the standard basis of V, then {e_1, ie_1, ..., e_n, ie_n} is the standard basis
+/// of V_R, so that dim_R(V_R) = 2 dim_C(V) and the entries of a vector are the real and imaginary parts
+/// of its complex entries in turn.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Realification<V>(pub V);
+
+// Operator implementations for realified vectors with realified vectors.
+impl<V: VectorSpace<Complex>> Neg for Realification<V> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+impl<V: VectorSpace<Complex> + Clone> Add<&Self> for Realification<V> {
+    type Output = Self;
+    fn add(mut self, rhs: &Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl<V: VectorSpace<Complex>> Add<Self> for Realification<V> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl<V: VectorSpace<Complex> + Clone> Sub<&Self> for Realification<V> {
+    type Output = Self;
+    fn sub(mut self, rhs: &Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl<V: VectorSpace<Complex>> Sub<Self> for Realification<V> {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl<V: VectorSpace<Complex> + Clone> AddAssign<&Self> for Realification<V> {
+    fn add_assign(&mut self, rhs: &Self) {
+        self.0 += rhs.0.clone();
+    }
+}
+impl<V: VectorSpace<Complex>> AddAssign<Self> for Realification<V> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+impl<V: VectorSpace<Complex> + Clone> SubAssign<&Self> for Realification<V> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.0 -= rhs.0.clone();
+    }
+}
+impl<V: VectorSpace<Complex>> SubAssign<Self> for Realification<V> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+// Operator implementations for realified vectors with real scalars.
+impl<T: Into<Real>, V: VectorSpace<Complex>> Mul<T> for Realification<V> {
+    type Output = Self;
+    fn mul(mut self, rhs: T) -> Self {
+        self *= rhs;
+        self
+    }
+}
+impl<T: Into<Real>, V: VectorSpace<Complex>> Div<T> for Realification<V> {
+    type Output = Self;
+    fn div(mut self, rhs: T) -> Self {
+        self /= rhs;
+        self
+    }
+}
+impl<T: Into<Real>, V: VectorSpace<Complex>> MulAssign<T> for Realification<V> {
+    fn mul_assign(&mut self, rhs: T) {
+        self.0 *= Complex::from(rhs.into());
+    }
+}
+impl<T: Into<Real>, V: VectorSpace<Complex>> DivAssign<T> for Realification<V> {
+    fn div_assign(&mut self, rhs: T) {
+        self.0 /= Complex::from(rhs.into());
+    }
+}
+
+impl<V: fmt::Display> fmt::Display for Realification<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<V: Entries<Complex>> Entries<Real> for Realification<V> {
+    fn entries(&self) -> Vec<Real> {
+        self.0.entries().iter().flat_map(|z| vec![z.re, z.im]).collect()
+    }
+}
+
+impl<V: VectorSpace<Complex> + Clone> VectorSpace<Real> for Realification<V> {
+    fn zero() -> Self {
+        Self(V::zero())
+    }
+}
+
+impl<V: FiniteDimensional<Complex>> FiniteDimensional<Real> for Realification<V> {
+    fn dim() -> usize {
+        2 * V::dim()
+    }
+
+    fn from_entries(entries: &[Real]) -> Self {
+        let complex: Vec<Complex> = entries.chunks(2).map(|z| Complex::new(z[0], z[1])).collect();
+        Self(V::from_entries(&complex))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Complexify, Realification};
+    use crate::field::{Complex, Real};
+    use crate::vector_space::{Entries, FiniteDimensional, Tuple};
+    use crate::{tuple, cmplx};
+
+    #[test]
+    fn complexify_arithmetic() {
+        let x = Complexify::new(tuple![1.0, 2.0], tuple![3.0, 4.0]);
+        assert_eq!(x.clone() * cmplx!(0, 1), Complexify::new(tuple![-3.0, -4.0], tuple![1.0, 2.0]));
+        assert_eq!(x.clone() * 2.0, Complexify::new(tuple![2.0, 4.0], tuple![6.0, 8.0]));
+        assert_eq!(x.conjugate(), Complexify::new(tuple![1.0, 2.0], tuple![-3.0, -4.0]));
+        assert_eq!(x.entries(), vec![cmplx!(1, 3), cmplx!(2, 4)]);
+        assert_eq!(Complexify::from_entries(&x.entries()), x);
+        assert_eq!(Complexify::from(tuple![1.0, 2.0]).conjugate(), Complexify::from(tuple![1.0, 2.0]));
+        assert_eq!(<Complexify<Tuple<Real, 3>> as FiniteDimensional<Complex>>::dim(), 3);
+    }
+
+    #[test]
+    fn realification_basis() {
+        assert_eq!(<Realification<Tuple<Complex, 2>> as FiniteDimensional<Real>>::dim(), 4);
+        let basis = Realification::<Tuple<Complex, 2>>::standard_basis();
+        assert_eq!(basis[1], Realification(tuple![cmplx!(0, 1), cmplx!(0)]));
+        let x = Realification(tuple![cmplx!(1, 2), cmplx!(3, -1)]);
+        assert_eq!(x.entries(), vec![1.0, 2.0, 3.0, -1.0]);
+        assert_eq!(x.clone() * 2.0, Realification(tuple![cmplx!(2, 4), cmplx!(6, -2)]));
+        assert_eq!(Realification::from_entries(&x.entries()), x);
+    }
+}
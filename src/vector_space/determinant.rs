@@ -0,0 +1,227 @@
+use crate::field::Field;
+use super::{Matrix, VectorSpace};
+use super::echelon::find_pivot;
+
+impl<F: Field, const N: usize> Matrix<F, N, N> {
+    /// Returns the determinant det(A), computed by Gaussian elimination. Interchanging two rows negates
+    /// the determinant and adding a multiple of one row to another leaves it unchanged (4.5, 4.6),
+    /// so det(A) is ± the product of the pivots of an upper triangular form of A.
+    /// Only field operations are used, so this is exact over any exact field, and partial pivoting
+    /// is used over `Real` and `Complex`. Small pivots are not rounded to zero, so a nearly singular
+    /// matrix has a small nonzero determinant.
+    pub fn determinant(&self) -> F {
+        eliminate(self.row_vecs())
+    }
+
+    /// Returns det(A) by cofactor expansion along row i, as Σ_j (-1)^(i+j) A_ij det(Ã_ij) (4.4).
+    /// The minors are expanded recursively along their first rows, which takes time proportional to N!.
+    pub fn cofactor_expansion_along_row(&self, i: usize) -> F {
+        expand_along_row(&self.row_vecs(), i)
+    }
+
+    /// Returns det(A) by cofactor expansion along column j, as Σ_i (-1)^(i+j) A_ij det(Ã_ij).
+    /// This is expansion along row j of Aᵗ, since det(Aᵗ) = det(A) (4.8).
+    pub fn cofactor_expansion_along_column(&self, j: usize) -> F {
+        expand_along_row(&self.transpose().row_vecs(), j)
+    }
+
+    /// Returns det(A) by the Leibniz formula Σ_σ sgn(σ) A_1σ(1) ⋯ A_Nσ(N), summing over all N! permutations σ.
+    pub fn leibniz_determinant(&self) -> F {
+        let mut accum = F::zero();
+        for (sigma, even) in permutations(N) {
+            let mut term = F::one();
+            for i in 0..N {
+                term *= self[(i, sigma[i])];
+            }
+            if even {
+                accum += term;
+            } else {
+                accum -= term;
+            }
+        }
+        accum
+    }
+
+    /// Returns the K × K matrix Ã_ij obtained by deleting row i and column j, where K = N - 1.
+    /// Panics if K is not N - 1.
+    pub fn minor<const K: usize>(&self, i: usize, j: usize) -> Matrix<F, K, K> {
+        assert_eq!(K + 1, N, "a minor of a {}x{} matrix is not {}x{}", N, N, K, K);
+        let rows = delete(&self.row_vecs(), i, j);
+        let mut out = Matrix::zero();
+        for r in 0..K {
+            for c in 0..K {
+                out[(r, c)] = rows[r][c];
+            }
+        }
+        out
+    }
+
+    /// Returns the cofactor (-1)^(i+j) det(Ã_ij) of the entry A_ij.
+    pub fn cofactor(&self, i: usize, j: usize) -> F {
+        sign(i + j, eliminate(delete(&self.row_vecs(), i, j)))
+    }
+
+    /// Returns the matrix of cofactors, whose ij'th entry is (-1)^(i+j) det(Ã_ij).
+    pub fn cofactor_matrix(&self) -> Self {
+        let mut out = Self::zero();
+        for i in 0..N {
+            for j in 0..N {
+                out[(i, j)] = self.cofactor(i, j);
+            }
+        }
+        out
+    }
+
+    /// Returns the classical adjoint adj(A), the transpose of the cofactor matrix.
+    /// It satisfies A adj(A) = adj(A) A = det(A) I, so A^-1 = adj(A) / det(A) when A is invertible.
+    pub fn adjugate(&self) -> Self {
+        self.cofactor_matrix().transpose()
+    }
+
+    fn row_vecs(&self) -> Vec<Vec<F>> {
+        (0..N).map(|i| self[i].to_vec()).collect()
+    }
+}
+
+/// Returns the determinant of a square matrix given by its rows, by reducing it to upper triangular form.
+/// The pivot search has zero tolerance, so the determinant is zero only when a column is exactly zero.
+fn eliminate<F: Field>(mut rows: Vec<Vec<F>>) -> F {
+    let n = rows.len();
+    let mut det = F::one();
+    for col in 0..n {
+        let found = match find_pivot(&mut rows, col, col, 0.0) {
+            Some(r) => r,
+            None => return F::zero(),
+        };
+        if found != col {
+            rows.swap(col, found);
+            det = -det;
+        }
+        let pivot = rows[col][col];
+        det *= pivot;
+        let (upper, lower) = rows.split_at_mut(col + 1);
+        for row in lower {
+            let factor = row[col] / pivot;
+            if factor == F::zero() {
+                continue;
+            }
+            for (entry, &above) in row[col..].iter_mut().zip(&upper[col][col..]) {
+                *entry -= above * factor;
+            }
+        }
+    }
+    det
+}
+
+/// Returns the determinant of a square matrix given by its rows, by cofactor expansion along row i
+/// and then along the first row of each minor.
+fn expand_along_row<F: Field>(rows: &[Vec<F>], i: usize) -> F {
+    if rows.is_empty() {
+        return F::one();
+    }
+    let mut accum = F::zero();
+    for j in 0..rows.len() {
+        if rows[i][j] != F::zero() {
+            accum += sign(i + j, rows[i][j] * expand_along_row(&delete(rows, i, j), 0));
+        }
+    }
+    accum
+}
+
+/// Returns the rows with row i and column j deleted.
+fn delete<F: Field>(rows: &[Vec<F>], i: usize, j: usize) -> Vec<Vec<F>> {
+    rows.iter().enumerate()
+        .filter(|&(r, _)| r != i)
+        .map(|(_, row)| row.iter().enumerate().filter(|&(c, _)| c != j).map(|(_, &x)| x).collect())
+        .collect()
+}
+
+/// Returns (-1)^k x.
+fn sign<F: Field>(k: usize, x: F) -> F {
+    if k % 2 == 1 { -x } else { x }
+}
+
+/// Returns the permutations of 0, ..., n - 1, each with whether it is even.
+/// Each permutation of n - 1 elements is extended by inserting n - 1 at every position, and
+/// inserting it at position k composes the permutation with a cycle of length n - k.
+fn permutations(n: usize) -> Vec<(Vec<usize>, bool)> {
+    if n == 0 {
+        return vec![(vec![], true)];
+    }
+    let mut out = Vec::new();
+    for (sigma, even) in permutations(n - 1) {
+        for k in 0..n {
+            let mut extended = sigma.clone();
+            extended.insert(k, n - 1);
+            out.push((extended, even != ((n - 1 - k) % 2 == 1)));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::permutations;
+    use crate::field::{IntMod2, Rational};
+    use crate::vector_space::{Matrix, determinant};
+    use crate::{tuple, matrix};
+
+    #[test]
+    fn determinant_methods_agree() {
+        // 4.2 Example 3
+        let a = matrix![[1, 3, -3], [-3, -5, 2], [-4, 4, -6]];
+        assert_eq!(a.determinant(), Rational::from(40));
+        assert_eq!(a.leibniz_determinant(), Rational::from(40));
+        for k in 0..3 {
+            assert_eq!(a.cofactor_expansion_along_row(k), Rational::from(40));
+            assert_eq!(a.cofactor_expansion_along_column(k), Rational::from(40));
+        }
+        assert_eq!(a.determinant(), determinant(&[tuple![1, 3, -3], tuple![-3, -5, 2], tuple![-4, 4, -6]]));
+
+        let b = matrix![[0, 1, 2, 3], [1, 0, 1, 2], [2, 1, 0, 1], [3, 2, 1, 0]];
+        assert_eq!(b.determinant(), b.leibniz_determinant());
+        assert_eq!(b.determinant(), b.cofactor_expansion_along_column(2));
+        assert_eq!(matrix![[1, 2], [2, 4]].determinant(), Rational::from(0));
+        assert_eq!(Matrix::<Rational, 0, 0>::identity().determinant(), Rational::from(1));
+
+        let (o, i) = (IntMod2::Zero, IntMod2::One);
+        let c = matrix![[o, i, i], [i, o, i], [i, i, o]];
+        assert_eq!(c.determinant(), o);
+        assert_eq!(c.leibniz_determinant(), o);
+        assert_eq!(matrix![[o, i], [i, i]].determinant(), i);
+
+        assert!((matrix![[1e-20, 1.0], [1.0, 1.0]].determinant() + 1.0).abs() < 1e-12);
+
+        let nearly_singular = matrix![[1.0, 1.0], [1.0, 1.0 + 1e-11]];
+        let leibniz = nearly_singular.leibniz_determinant();
+        assert!(leibniz != 0.0);
+        assert!((nearly_singular.determinant() - leibniz).abs() <= 1e-12 * leibniz.abs());
+        assert_eq!(nearly_singular.rank(), 1);
+    }
+
+    #[test]
+    fn minors_and_adjugate() {
+        let a = matrix![[1, 3, -3], [-3, -5, 2], [-4, 4, -6]];
+        assert_eq!(a.minor(1, 2), matrix![[1, 3], [-4, 4]]);
+        assert_eq!(a.cofactor(1, 2), Rational::from(-16));
+        assert_eq!(a.cofactor_matrix()[(1, 2)], Rational::from(-16));
+        let adjugate = a.adjugate();
+        let identity: Matrix<Rational, 3, 3> = Matrix::identity();
+        assert_eq!(a * adjugate, identity * a.determinant());
+        assert_eq!(adjugate * a, identity * a.determinant());
+        assert_eq!(a.inverse(), Some(adjugate / a.determinant()));
+    }
+
+    #[test]
+    fn permutation_signs() {
+        let all = permutations(4);
+        assert_eq!(all.len(), 24);
+        assert_eq!(all.iter().filter(|(_, even)| *even).count(), 12);
+        for (sigma, even) in all {
+            let inversions = (0..4).flat_map(|i| (i + 1..4).map(move |j| (i, j)))
+                .filter(|&(i, j)| sigma[i] > sigma[j])
+                .count();
+            assert_eq!(even, inversions % 2 == 0);
+        }
+    }
+}
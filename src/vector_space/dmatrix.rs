@@ -0,0 +1,329 @@
+use crate::field::Field;
+use super::{Entries, Matrix, DVector, DimensionMismatch};
+use super::echelon::{row_reduce, null_space};
+use std::ops::{Mul, Div, MulAssign, DivAssign, Index, IndexMut, Neg};
+use std::convert::TryFrom;
+
+/// A type representing a matrix over a given field, whose shape is chosen at runtime.
+/// Entries are stored row by row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DMatrix<F: Field> {
+    rows: usize,
+    columns: usize,
+    entries: Vec<F>,
+}
+
+impl<F: Field> DMatrix<F> {
+    /// Creates the zero matrix with the given number of rows and columns.
+    pub fn zeros(rows: usize, columns: usize) -> Self {
+        Self {
+            rows,
+            columns,
+            entries: vec![F::zero(); rows * columns]
+        }
+    }
+
+    /// Creates the n × n identity matrix.
+    pub fn identity(n: usize) -> Self {
+        let mut out = Self::zeros(n, n);
+        for i in 0..n {
+            out[(i, i)] = F::one();
+        }
+        out
+    }
+
+    /// Creates a matrix from a list of rows, or returns an error if the rows have different lengths.
+    /// An empty list gives the 0 × 0 matrix.
+    pub fn from_rows(rows: Vec<Vec<F>>) -> Result<Self, DimensionMismatch> {
+        let columns = rows.first().map_or(0, |row| row.len());
+        for row in rows.iter() {
+            DimensionMismatch::check((1, columns), (1, row.len()))?;
+        }
+        Ok(Self {
+            rows: rows.len(),
+            columns,
+            entries: rows.concat()
+        })
+    }
+
+    /// Returns the shape (rows, columns) of the matrix.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.columns)
+    }
+
+    /// Returns the entry in row i and column j, or `None` if either is out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> Option<&F> {
+        if i < self.rows && j < self.columns {
+            Some(&self.entries[i * self.columns + j])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the rows of the matrix.
+    fn to_rows(&self) -> Vec<Vec<F>> {
+        (0..self.rows).map(|i| self.entries[i * self.columns..(i + 1) * self.columns].to_vec()).collect()
+    }
+
+    /// Returns the transpose of the matrix.
+    pub fn transpose(&self) -> Self {
+        let mut out = Self::zeros(self.columns, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.columns {
+                out[(j, i)] = self[(i, j)];
+            }
+        }
+        out
+    }
+
+    /// Returns the sum A + B, or an error if the shapes differ.
+    pub fn try_add(&self, other: &Self) -> Result<Self, DimensionMismatch> {
+        DimensionMismatch::check(self.shape(), other.shape())?;
+        let mut out = self.clone();
+        for (a, b) in out.entries.iter_mut().zip(other.entries.iter()) {
+            *a += *b;
+        }
+        Ok(out)
+    }
+
+    /// Returns the difference A - B, or an error if the shapes differ.
+    pub fn try_sub(&self, other: &Self) -> Result<Self, DimensionMismatch> {
+        DimensionMismatch::check(self.shape(), other.shape())?;
+        let mut out = self.clone();
+        for (a, b) in out.entries.iter_mut().zip(other.entries.iter()) {
+            *a -= *b;
+        }
+        Ok(out)
+    }
+
+    /// Returns the product AB, or an error if B doesn't have as many rows as A has columns.
+    pub fn try_mul(&self, other: &Self) -> Result<Self, DimensionMismatch> {
+        DimensionMismatch::check((self.columns, other.columns), other.shape())?;
+        let mut out = Self::zeros(self.rows, other.columns);
+        for i in 0..self.rows {
+            for j in 0..other.columns {
+                for k in 0..self.columns {
+                    out[(i, j)] += self[(i, k)] * other[(k, j)];
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Returns the product Ax, or an error if x doesn't have as many entries as A has columns.
+    pub fn try_apply(&self, x: &DVector<F>) -> Result<DVector<F>, DimensionMismatch> {
+        DimensionMismatch::check((self.columns, 1), (x.len(), 1))?;
+        let mut out = DVector::zeros(self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.columns {
+                out[i] += self[(i, j)] * x[j];
+            }
+        }
+        Ok(out)
+    }
+
+    /// Returns the rank of the matrix.
+    pub fn rank(&self) -> usize {
+        row_reduce(&mut self.to_rows()).len()
+    }
+
+    /// Returns a basis for the null space {x : Ax = 0}.
+    pub fn kernel(&self) -> Vec<DVector<F>> {
+        let mut rows = self.to_rows();
+        let pivots = row_reduce(&mut rows);
+        null_space(&rows, &pivots, self.columns).into_iter().map(DVector::new).collect()
+    }
+
+    /// Returns the inverse of the matrix, or `None` if the matrix is not invertible.
+    /// Returns an error if the matrix is not square.
+    pub fn inverse(&self) -> Result<Option<Self>, DimensionMismatch> {
+        let n = self.rows;
+        DimensionMismatch::check((n, n), self.shape())?;
+        let identity = Self::identity(n);
+        let mut rows: Vec<Vec<F>> = self.to_rows().into_iter().zip(identity.to_rows())
+            .map(|(mut row, e)| {
+                row.extend(e);
+                row
+            })
+            .collect();
+        if row_reduce(&mut rows).iter().take_while(|&&p| p < n).count() < n {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            rows: n,
+            columns: n,
+            entries: rows.iter().flat_map(|row| row[n..].to_vec()).collect()
+        }))
+    }
+
+    /// Returns a solution of the system Ax = b, or `None` if the system is inconsistent.
+    /// Free variables are set to zero. Returns an error if b doesn't have as many entries as A has rows.
+    pub fn solve(&self, b: &DVector<F>) -> Result<Option<DVector<F>>, DimensionMismatch> {
+        DimensionMismatch::check((self.rows, 1), (b.len(), 1))?;
+        let mut rows: Vec<Vec<F>> = self.to_rows().into_iter().enumerate()
+            .map(|(i, mut row)| {
+                row.push(b[i]);
+                row
+            })
+            .collect();
+        let pivots = row_reduce(&mut rows);
+        if pivots.last() == Some(&self.columns) {
+            return Ok(None);
+        }
+        let mut x = DVector::zeros(self.columns);
+        for (i, &p) in pivots.iter().enumerate() {
+            x[p] = rows[i][self.columns];
+        }
+        Ok(Some(x))
+    }
+}
+
+impl<F: Field, const M: usize, const N: usize> From<Matrix<F, M, N>> for DMatrix<F> {
+    fn from(a: Matrix<F, M, N>) -> Self {
+        Self {
+            rows: M,
+            columns: N,
+            entries: a.entries()
+        }
+    }
+}
+impl<F: Field, const M: usize, const N: usize> TryFrom<DMatrix<F>> for Matrix<F, M, N> {
+    type Error = DimensionMismatch;
+    fn try_from(a: DMatrix<F>) -> Result<Self, DimensionMismatch> {
+        DimensionMismatch::check((M, N), a.shape())?;
+        let mut out = [[F::zero(); N]; M];
+        for (i, row) in out.iter_mut().enumerate() {
+            row.copy_from_slice(&a.entries[i * N..(i + 1) * N]);
+        }
+        Ok(Matrix::from(out))
+    }
+}
+
+// Matrix indexing
+impl<F: Field> Index<(usize, usize)> for DMatrix<F> {
+    type Output = F;
+    fn index(&self, (i, j): (usize, usize)) -> &F {
+        assert!(i < self.rows && j < self.columns, "index ({}, {}) out of bounds for a {}x{} matrix", i, j, self.rows, self.columns);
+        &self.entries[i * self.columns + j]
+    }
+}
+impl<F: Field> IndexMut<(usize, usize)> for DMatrix<F> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut F {
+        assert!(i < self.rows && j < self.columns, "index ({}, {}) out of bounds for a {}x{} matrix", i, j, self.rows, self.columns);
+        &mut self.entries[i * self.columns + j]
+    }
+}
+
+// Operator implementations for matrices with scalars.
+impl<F: Field> Neg for DMatrix<F> {
+    type Output = Self;
+    fn neg(mut self) -> Self {
+        for a in self.entries.iter_mut() {
+            *a = -*a;
+        }
+        self
+    }
+}
+impl<T: Into<F>, F: Field> Mul<T> for DMatrix<F> {
+    type Output = Self;
+    fn mul(mut self, rhs: T) -> Self {
+        self *= rhs;
+        self
+    }
+}
+impl<T: Into<F>, F: Field> Div<T> for DMatrix<F> {
+    type Output = Self;
+    fn div(mut self, rhs: T) -> Self {
+        self /= rhs;
+        self
+    }
+}
+impl<T: Into<F>, F: Field> MulAssign<T> for DMatrix<F> {
+    fn mul_assign(&mut self, rhs: T) {
+        let scale = rhs.into();
+        for a in self.entries.iter_mut() {
+            *a *= scale;
+        }
+    }
+}
+impl<T: Into<F>, F: Field> DivAssign<T> for DMatrix<F> {
+    fn div_assign(&mut self, rhs: T) {
+        let scale = rhs.into();
+        for a in self.entries.iter_mut() {
+            *a /= scale;
+        }
+    }
+}
+
+impl<F: Field> Entries<F> for DMatrix<F> {
+    fn entries(&self) -> Vec<F> {
+        self.entries.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DMatrix;
+    use crate::field::{Int, Rational};
+    use crate::vector_space::{DVector, DimensionMismatch, Matrix};
+    use crate::{tuple, matrix};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn dmatrix_from_rows() {
+        let r = |x: Int| Rational::from(x);
+        let a = DMatrix::from_rows(vec![vec![r(1), r(2)], vec![r(3), r(4)]]).unwrap();
+        assert_eq!(a, DMatrix::from(matrix![[1, 2], [3, 4]]));
+        assert_eq!(a.shape(), (2, 2));
+        assert_eq!(a.get(1, 0), Some(&r(3)));
+        assert_eq!(a.get(2, 0), None);
+        assert_eq!(DMatrix::from_rows(vec![vec![r(1), r(2)], vec![r(3)]]), Err(DimensionMismatch { expected: (1, 2), found: (1, 1) }));
+    }
+
+    #[test]
+    fn dmatrix_arithmetic() {
+        let a = DMatrix::from(matrix![[1, 2, 0], [0, 1, 1]]);
+        let b = DMatrix::from(matrix![[1, 0], [0, 1], [1, 1]]);
+        assert_eq!(a.try_mul(&b), Ok(DMatrix::from(matrix![[1, 2], [1, 2]])));
+        assert_eq!(a.try_mul(&a), Err(DimensionMismatch { expected: (3, 3), found: (2, 3) }));
+        assert_eq!(a.try_add(&b.transpose()), Ok(DMatrix::from(matrix![[2, 2, 1], [0, 2, 2]])));
+        assert!(a.try_sub(&b).is_err());
+        assert_eq!(a.try_apply(&DVector::from(tuple![1, 1, 1])), Ok(DVector::from(tuple![3, 2])));
+        assert_eq!(Matrix::try_from(a.clone() * 2), Ok(matrix![[2, 4, 0], [0, 2, 2]]));
+        assert!(Matrix::<Rational, 3, 2>::try_from(a).is_err());
+    }
+
+    #[test]
+    fn dmatrix_algorithms() {
+        let a = DMatrix::from(matrix![[1, 2, 1], [2, 4, 2]]);
+        assert_eq!(a.rank(), 1);
+        assert_eq!(a.kernel().len(), 2);
+        assert_eq!(a.inverse(), Err(DimensionMismatch { expected: (2, 2), found: (2, 3) }));
+        assert_eq!(a.solve(&DVector::from(tuple![1, 2])), Ok(Some(DVector::from(tuple![1, 0, 0]))));
+        assert_eq!(a.solve(&DVector::from(tuple![1, 1])), Ok(None));
+
+        let b = DMatrix::from(matrix![[2, 1], [1, 1]]);
+        assert_eq!(b.inverse(), Ok(Some(DMatrix::from(matrix![[1, -1], [-1, 2]]))));
+        assert_eq!(DMatrix::from(matrix![[1, 1], [1, 1]]).inverse(), Ok(None));
+    }
+
+    #[test]
+    fn dmatrix_empty_shapes() {
+        let r = |x: Int| Rational::from(x);
+        // A 3 × 0 matrix sends the only vector of F^0 to zero.
+        let a = DMatrix::<Rational>::zeros(3, 0);
+        assert_eq!(a.rank(), 0);
+        assert!(a.kernel().is_empty());
+        assert_eq!(a.solve(&DVector::new(vec![r(1), r(1), r(1)])), Ok(None));
+        assert_eq!(a.solve(&DVector::zeros(3)), Ok(Some(DVector::zeros(0))));
+        assert_eq!(a.transpose().shape(), (0, 3));
+
+        // A 0 × 3 matrix sends all of F^3 to the only vector of F^0.
+        let b = DMatrix::<Rational>::zeros(0, 3);
+        assert_eq!(b.rank(), 0);
+        assert_eq!(b.kernel().len(), 3);
+        assert_eq!(b.solve(&DVector::zeros(0)), Ok(Some(DVector::zeros(3))));
+        assert_eq!(DMatrix::<Rational>::identity(0).inverse(), Ok(Some(DMatrix::zeros(0, 0))));
+        assert_eq!(a.try_mul(&b), Ok(DMatrix::zeros(3, 3)));
+    }
+}
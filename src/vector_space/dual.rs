@@ -0,0 +1,252 @@
+use crate::field::Field;
+use crate::linear_map::LinearMap;
+use super::{VectorSpace, Entries, FiniteDimensional, BoundedPolynom};
+use super::echelon::{row_reduce, null_space};
+use std::ops::{Add, Mul, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssign, Neg};
+use std::marker::PhantomData;
+use std::fmt;
+
+/// A type representing a linear functional on a finite-dimensional vector space V,
+/// making up the dual space V* = L(V, F).
+/// The functional is stored as its values f(e_1), ..., f(e_n) on the standard ordered basis of V.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Dual<F: Field, V>(Vec<F>, PhantomData<V>);
+
+impl<F: Field, V: FiniteDimensional<F>> Dual<F, V> {
+    /// Creates the linear functional given by the closure, which the caller is trusted to have made linear.
+    pub fn from_fn(f: impl Fn(&V) -> F) -> Self {
+        Self(V::standard_basis().iter().map(f).collect(), PhantomData)
+    }
+
+    /// Returns the dual basis β* = {f_1, ..., f_n} of an ordered basis β, where f_i(x) is the i'th
+    /// coordinate of x relative to β, so that f_i(v_j) = δ_ij (2.24). Assumes that β is a basis.
+    pub fn dual_basis<const N: usize>(beta: &[V; N]) -> [Self; N] {
+        let coordinates: Vec<_> = V::standard_basis().iter().map(|e| e.coordinates(beta)).collect();
+        std::array::from_fn(|i| Self(coordinates.iter().map(|c| c[i]).collect(), PhantomData))
+    }
+
+    /// Returns a basis for the annihilator S^0 = {f in V* : f(x) = 0 for all x in S} of a set of vectors.
+    pub fn annihilator(s: &[V]) -> Vec<Self> {
+        let mut rows: Vec<Vec<F>> = s.iter().map(|x| x.entries()).collect();
+        let pivots = row_reduce(&mut rows);
+        null_space(&rows, &pivots, V::dim()).into_iter().map(|f| Self(f, PhantomData)).collect()
+    }
+}
+
+impl<F: Field, V: FiniteDimensional<F>> Dual<F, Dual<F, V>> {
+    /// Returns the image of x under the natural isomorphism ψ: V -> V**, which is the
+    /// evaluation functional x̂(f) = f(x) (2.26).
+    pub fn double_dual(x: &V) -> Self {
+        Self(x.entries(), PhantomData)
+    }
+}
+
+impl<F: Field, const N: usize> Dual<F, BoundedPolynom<F, N>> {
+    /// Returns the functional f -> f(t) which evaluates polynomials at t.
+    pub fn evaluation(t: F) -> Self {
+        Self::from_fn(|f| f.polynom().eval_at(t))
+    }
+
+    /// Returns the functional f -> ∫₀¹ f(t) dt, which takes t^k to 1/(k+1).
+    /// Returns `None` if some k + 1 ≤ N + 1 is zero in F, as happens in characteristic 2 for N ≥ 1.
+    pub fn integral() -> Option<Self> {
+        let mut values = Vec::new();
+        let mut denom = F::zero();
+        for _ in 0..=N {
+            denom += F::one();
+            if denom == F::zero() {
+                return None;
+            }
+            values.push(F::one() / denom);
+        }
+        Some(Self(values, PhantomData))
+    }
+}
+
+impl<F: Field, V: Entries<F>> LinearMap<F, V, F> for Dual<F, V> {
+    fn apply(&self, x: &V) -> F {
+        let mut accum = F::zero();
+        for (value, entry) in self.0.iter().zip(x.entries()) {
+            accum += *value * entry;
+        }
+        accum
+    }
+}
+
+// Operator implementations for functionals with functionals.
+impl<F: Field, V> Neg for Dual<F, V> {
+    type Output = Self;
+    fn neg(mut self) -> Self {
+        for value in self.0.iter_mut() {
+            *value = -*value;
+        }
+        self
+    }
+}
+impl<F: Field, V> Add<&Self> for Dual<F, V> {
+    type Output = Self;
+    fn add(mut self, rhs: &Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl<F: Field, V> Add<Self> for Dual<F, V> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl<F: Field, V> Sub<&Self> for Dual<F, V> {
+    type Output = Self;
+    fn sub(mut self, rhs: &Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl<F: Field, V> Sub<Self> for Dual<F, V> {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl<F: Field, V> AddAssign<&Self> for Dual<F, V> {
+    fn add_assign(&mut self, rhs: &Self) {
+        for i in 0..self.0.len() {
+            self.0[i] += rhs.0[i];
+        }
+    }
+}
+impl<F: Field, V> AddAssign<Self> for Dual<F, V> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+impl<F: Field, V> SubAssign<&Self> for Dual<F, V> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        for i in 0..self.0.len() {
+            self.0[i] -= rhs.0[i];
+        }
+    }
+}
+impl<F: Field, V> SubAssign<Self> for Dual<F, V> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+// Operator implementations for functionals with scalars.
+impl<T: Into<F>, F: Field, V> Mul<T> for Dual<F, V> {
+    type Output = Self;
+    fn mul(mut self, rhs: T) -> Self {
+        self *= rhs;
+        self
+    }
+}
+impl<T: Into<F>, F: Field, V> Div<T> for Dual<F, V> {
+    type Output = Self;
+    fn div(mut self, rhs: T) -> Self {
+        self /= rhs;
+        self
+    }
+}
+impl<T: Into<F>, F: Field, V> MulAssign<T> for Dual<F, V> {
+    fn mul_assign(&mut self, rhs: T) {
+        let scale = rhs.into();
+        for value in self.0.iter_mut() {
+            *value *= scale;
+        }
+    }
+}
+impl<T: Into<F>, F: Field, V> DivAssign<T> for Dual<F, V> {
+    fn div_assign(&mut self, rhs: T) {
+        let scale = rhs.into();
+        for value in self.0.iter_mut() {
+            *value /= scale;
+        }
+    }
+}
+
+impl<F: Field, V> fmt::Display for Dual<F, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, value) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", value)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<F: Field, V> Entries<F> for Dual<F, V> {
+    fn entries(&self) -> Vec<F> {
+        self.0.clone()
+    }
+}
+
+impl<F: Field, V: FiniteDimensional<F>> VectorSpace<F> for Dual<F, V> {
+    fn zero() -> Self {
+        Self(vec![F::zero(); V::dim()], PhantomData)
+    }
+}
+
+// The standard ordered basis of V* is the dual basis of the standard ordered basis of V.
+impl<F: Field, V: FiniteDimensional<F>> FiniteDimensional<F> for Dual<F, V> {
+    fn dim() -> usize {
+        V::dim()
+    }
+
+    fn from_entries(entries: &[F]) -> Self {
+        Self(entries.to_vec(), PhantomData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dual;
+    use crate::field::{IntMod2, Rational};
+    use crate::linear_map::LinearMap;
+    use crate::vector_space::{BoundedPolynom, FiniteDimensional, Tuple};
+    use crate::{tuple, polynom};
+
+    #[test]
+    fn dual_basis() {
+        // 2.6 Example 4: the dual basis of {(2, 1), (3, 1)} is f1(x, y) = -x + 3y, f2(x, y) = x - 2y.
+        let beta = [tuple![2, 1], tuple![3, 1]];
+        let [f1, f2] = Dual::dual_basis(&beta);
+        assert_eq!(f1, Dual::from_fn(|x: &Tuple<Rational, 2>| -x[0] + x[1] * 3));
+        assert_eq!(f2, Dual::from_fn(|x: &Tuple<Rational, 2>| x[0] - x[1] * 2));
+        assert_eq!(f1.apply(&beta[0]), Rational::from(1));
+        assert_eq!(f1.apply(&beta[1]), Rational::from(0));
+    }
+
+    #[test]
+    fn dual_double_dual() {
+        let x = tuple![3, -1];
+        let f = Dual::from_fn(|v: &Tuple<Rational, 2>| v[0] * 2 + v[1]);
+        assert_eq!(Dual::double_dual(&x).apply(&f), f.apply(&x));
+        assert_eq!(<Dual<Rational, Dual<Rational, Tuple<Rational, 2>>> as FiniteDimensional<Rational>>::dim(), 2);
+    }
+
+    #[test]
+    fn dual_annihilator() {
+        let s = [tuple![1, 1, 0], tuple![2, 2, 0]];
+        let annihilator = Dual::annihilator(&s);
+        assert_eq!(annihilator.len(), 2);
+        for f in annihilator.iter() {
+            assert_eq!(f.apply(&s[0]), Rational::from(0));
+        }
+    }
+
+    #[test]
+    fn dual_polynomial_functionals() {
+        let f = BoundedPolynom::<Rational, 2>::new(polynom![1, 1, 1]).unwrap();
+        assert_eq!(Dual::evaluation(Rational::from(2)).apply(&f), Rational::from(7));
+        assert_eq!(Dual::integral().unwrap().apply(&f), Rational::from(11) / 6);
+        assert!(Dual::<IntMod2, BoundedPolynom<IntMod2, 1>>::integral().is_none());
+        assert!(Dual::<IntMod2, BoundedPolynom<IntMod2, 0>>::integral().is_some());
+    }
+}
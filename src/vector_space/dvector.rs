@@ -0,0 +1,210 @@
+use crate::field::Field;
+use super::{Entries, Tuple};
+use std::ops::{Mul, Div, MulAssign, DivAssign, Index, IndexMut, Neg};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+/// The error returned when an operation on runtime-sized vectors or matrices is given operands
+/// whose shapes don't fit together. Shapes are given as (rows, columns), with vectors as columns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DimensionMismatch {
+    pub expected: (usize, usize),
+    pub found: (usize, usize),
+}
+
+impl DimensionMismatch {
+    pub(crate) fn check(expected: (usize, usize), found: (usize, usize)) -> Result<(), Self> {
+        if expected == found {
+            Ok(())
+        } else {
+            Err(Self { expected, found })
+        }
+    }
+}
+
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "dimension mismatch: expected {}x{}, found {}x{}",
+            self.expected.0, self.expected.1, self.found.0, self.found.1)
+    }
+}
+
+impl Error for DimensionMismatch {}
+
+/// A type representing a column vector of elements from a field, whose length is chosen at runtime.
+#[derive(PartialEq, Debug, Clone)]
+pub struct DVector<F: Field>(Vec<F>);
+
+impl<F: Field> DVector<F> {
+    /// Creates a vector with the given entries.
+    pub fn new(entries: Vec<F>) -> Self {
+        Self(entries)
+    }
+
+    /// Creates the zero vector of length n.
+    pub fn zeros(n: usize) -> Self {
+        Self(vec![F::zero(); n])
+    }
+
+    /// Returns the length of the vector.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the vector has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the i'th entry, or `None` if i is out of bounds.
+    pub fn get(&self, i: usize) -> Option<&F> {
+        self.0.get(i)
+    }
+
+    /// Returns the sum x + y, or an error if the lengths differ.
+    pub fn try_add(&self, other: &Self) -> Result<Self, DimensionMismatch> {
+        DimensionMismatch::check((self.len(), 1), (other.len(), 1))?;
+        Ok(Self(self.0.iter().zip(other.0.iter()).map(|(x, y)| *x + *y).collect()))
+    }
+
+    /// Returns the difference x - y, or an error if the lengths differ.
+    pub fn try_sub(&self, other: &Self) -> Result<Self, DimensionMismatch> {
+        DimensionMismatch::check((self.len(), 1), (other.len(), 1))?;
+        Ok(Self(self.0.iter().zip(other.0.iter()).map(|(x, y)| *x - *y).collect()))
+    }
+
+    /// Returns Σ x_i y_i, or an error if the lengths differ.
+    pub fn try_dot(&self, other: &Self) -> Result<F, DimensionMismatch> {
+        DimensionMismatch::check((self.len(), 1), (other.len(), 1))?;
+        let mut accum = F::zero();
+        for (x, y) in self.0.iter().zip(other.0.iter()) {
+            accum += *x * *y;
+        }
+        Ok(accum)
+    }
+}
+
+impl<F: Field> From<Vec<F>> for DVector<F> {
+    fn from(entries: Vec<F>) -> Self {
+        Self(entries)
+    }
+}
+impl<F: Field, const N: usize> From<Tuple<F, N>> for DVector<F> {
+    fn from(x: Tuple<F, N>) -> Self {
+        Self(x.entries())
+    }
+}
+impl<F: Field, const N: usize> TryFrom<DVector<F>> for Tuple<F, N> {
+    type Error = DimensionMismatch;
+    fn try_from(x: DVector<F>) -> Result<Self, DimensionMismatch> {
+        DimensionMismatch::check((N, 1), (x.len(), 1))?;
+        let mut out = [F::zero(); N];
+        out.copy_from_slice(&x.0);
+        Ok(Tuple::from(out))
+    }
+}
+
+// Vector indexing
+impl<F: Field> Index<usize> for DVector<F> {
+    type Output = F;
+    fn index(&self, i: usize) -> &F {
+        &self.0[i]
+    }
+}
+impl<F: Field> IndexMut<usize> for DVector<F> {
+    fn index_mut(&mut self, i: usize) -> &mut F {
+        &mut self.0[i]
+    }
+}
+
+// Operator implementations for vectors with scalars.
+impl<F: Field> Neg for DVector<F> {
+    type Output = Self;
+    fn neg(mut self) -> Self {
+        for x in self.0.iter_mut() {
+            *x = -*x;
+        }
+        self
+    }
+}
+impl<T: Into<F>, F: Field> Mul<T> for DVector<F> {
+    type Output = Self;
+    fn mul(mut self, rhs: T) -> Self {
+        self *= rhs;
+        self
+    }
+}
+impl<T: Into<F>, F: Field> Div<T> for DVector<F> {
+    type Output = Self;
+    fn div(mut self, rhs: T) -> Self {
+        self /= rhs;
+        self
+    }
+}
+impl<T: Into<F>, F: Field> MulAssign<T> for DVector<F> {
+    fn mul_assign(&mut self, rhs: T) {
+        let scale = rhs.into();
+        for x in self.0.iter_mut() {
+            *x *= scale;
+        }
+    }
+}
+impl<T: Into<F>, F: Field> DivAssign<T> for DVector<F> {
+    fn div_assign(&mut self, rhs: T) {
+        let scale = rhs.into();
+        for x in self.0.iter_mut() {
+            *x /= scale;
+        }
+    }
+}
+
+impl<F: Field> Entries<F> for DVector<F> {
+    fn entries(&self) -> Vec<F> {
+        self.0.clone()
+    }
+}
+
+impl<F: Field> fmt::Display for DVector<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "(empty vector)");
+        }
+        write!(f, "(")?;
+        for i in 0..self.len()-1 {
+            write!(f, "{}, ", self[i])?;
+        }
+        write!(f, "{})", self[self.len()-1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DVector, DimensionMismatch};
+    use crate::field::Rational;
+    use crate::vector_space::Tuple;
+    use crate::tuple;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn dvector_arithmetic() {
+        let x = DVector::from(tuple![1, 2, 3]);
+        let y = DVector::from(tuple![4, 5, 6]);
+        assert_eq!(x.try_add(&y), Ok(DVector::from(tuple![5, 7, 9])));
+        assert_eq!(y.try_sub(&x), Ok(DVector::from(tuple![3, 3, 3])));
+        assert_eq!(x.try_dot(&y), Ok(Rational::from(32)));
+        assert_eq!(x.to_string(), "(1, 2, 3)");
+    }
+
+    #[test]
+    fn dvector_mismatch() {
+        let x = DVector::from(tuple![1, 2, 3]);
+        let z = DVector::from(tuple![1, 2]);
+        let error = DimensionMismatch { expected: (3, 1), found: (2, 1) };
+        assert_eq!(x.try_add(&z), Err(error));
+        assert_eq!(x.try_dot(&z), Err(error));
+        assert_eq!(Tuple::<Rational, 2>::try_from(x.clone()), Err(DimensionMismatch { expected: (2, 1), found: (3, 1) }));
+        assert_eq!(Tuple::try_from(x), Ok(tuple![1, 2, 3]));
+        assert_eq!(z.get(2), None);
+    }
+}
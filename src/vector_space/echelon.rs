@@ -0,0 +1,200 @@
+use crate::field::{Field, Real};
+use super::{Matrix, VectorSpace};
+
+/// Entries of an inexact field smaller than this, relative to the largest entry originally in their
+/// column, are treated as zero.
+const TOLERANCE: Real = 1e-10;
+
+/// Puts a list of equal-length rows into reduced row echelon form in-place via Gauss-Jordan elimination.
+/// Returns the pivot column of each nonzero row, in order. Zero rows are left at the bottom.
+/// Over inexact fields the largest entry of each column is used as its pivot (partial pivoting).
+pub(crate) fn row_reduce<F: Field>(rows: &mut [Vec<F>]) -> Vec<usize> {
+    let mut pivots = Vec::new();
+    let width = match rows.first() {
+        Some(row) => row.len(),
+        None => return pivots,
+    };
+    let tolerances = column_tolerances(rows);
+    let mut pivot_row = 0;
+    for col in 0..width {
+        if pivot_row == rows.len() {
+            break;
+        }
+        let found = match find_pivot(rows, pivot_row, col, tolerances[col]) {
+            Some(r) => r,
+            None => continue,
+        };
+        rows.swap(pivot_row, found);
+
+        let scale = rows[pivot_row][col];
+        for entry in rows[pivot_row].iter_mut() {
+            *entry /= scale;
+        }
+        rows[pivot_row][col] = F::one();
+        let pivot = rows[pivot_row].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            let factor = row[col];
+            if r == pivot_row || factor == F::zero() {
+                continue;
+            }
+            for (entry, &above) in row[col..].iter_mut().zip(&pivot[col..]) {
+                *entry -= above * factor;
+            }
+            row[col] = F::zero();
+        }
+        pivots.push(col);
+        pivot_row += 1;
+    }
+    pivots
+}
+
+/// Returns, for each column, the size below which entries of an inexact field are treated as zero.
+fn column_tolerances<F: Field>(rows: &[Vec<F>]) -> Vec<Real> {
+    let width = rows.first().map_or(0, |row| row.len());
+    (0..width)
+        .map(|col| rows.iter().filter_map(|row| row[col].magnitude()).fold(0.0, Real::max) * TOLERANCE)
+        .collect()
+}
+
+/// Returns the row at or below `start` to pivot on in the given column, or `None` if the column is zero there.
+/// Over exact fields this is the first nonzero entry. Over inexact fields it is the largest entry,
+/// and if that is within the tolerance of zero, the entries are set to zero.
+pub(crate) fn find_pivot<F: Field>(rows: &mut [Vec<F>], start: usize, col: usize, tolerance: Real) -> Option<usize> {
+    let first = (start..rows.len()).find(|&r| rows[r][col] != F::zero())?;
+    if rows[first][col].magnitude().is_none() {
+        return Some(first);
+    }
+    let size = |r: usize| rows[r][col].magnitude().unwrap_or(0.0);
+    let largest = (first..rows.len()).fold(first, |best, r| if size(r) > size(best) { r } else { best });
+    if size(largest) <= tolerance {
+        for row in rows[start..].iter_mut() {
+            row[col] = F::zero();
+        }
+        return None;
+    }
+    Some(largest)
+}
+
+/// Given rows in reduced row echelon form with the given pivot columns, returns a basis for the
+/// solutions of the homogeneous system they describe, with one vector for each free column.
+pub(crate) fn null_space<F: Field>(rows: &[Vec<F>], pivots: &[usize], width: usize) -> Vec<Vec<F>> {
+    let mut basis = Vec::new();
+    for free in (0..width).filter(|c| !pivots.contains(c)) {
+        let mut solution = vec![F::zero(); width];
+        solution[free] = F::one();
+        for (r, &p) in pivots.iter().enumerate() {
+            solution[p] = -rows[r][free];
+        }
+        basis.push(solution);
+    }
+    basis
+}
+
+/// The reduced row echelon form R of a matrix A, as returned by `Matrix::rref`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rref<F: Field, const M: usize, const N: usize> {
+    /// The reduced row echelon form R.
+    pub reduced: Matrix<F, M, N>,
+    /// The column of the leading one of each nonzero row of R, in order.
+    pub pivots: Vec<usize>,
+    /// The rank of A, which is the number of nonzero rows of R (3.4).
+    pub rank: usize,
+    /// An invertible matrix P with PA = R, which is a product of elementary matrices (3.6).
+    pub transform: Matrix<F, M, M>,
+}
+
+impl<F: Field, const M: usize, const N: usize> Matrix<F, M, N> {
+    /// Returns the reduced row echelon form of the matrix together with its pivot columns, rank
+    /// and a matrix P with PA = R. P is found by row reducing the augmented matrix (A|I) to (R|P).
+    /// Over exact fields such as `Rational` and `IntMod2` the result is exact, and over `Real` and
+    /// `Complex` partial pivoting is used.
+    pub fn rref(&self) -> Rref<F, M, N> {
+        let mut rows: Vec<Vec<F>> = (0..M).map(|i| {
+            let mut row = self[i].to_vec();
+            row.extend((0..M).map(|j| if i == j { F::one() } else { F::zero() }));
+            row
+        }).collect();
+        let pivots: Vec<usize> = row_reduce(&mut rows).into_iter().take_while(|&p| p < N).collect();
+        let mut reduced = Matrix::zero();
+        let mut transform = Matrix::zero();
+        for i in 0..M {
+            for j in 0..N {
+                reduced[(i, j)] = rows[i][j];
+            }
+            for j in 0..M {
+                transform[(i, j)] = rows[i][N + j];
+            }
+        }
+        Rref {
+            reduced,
+            rank: pivots.len(),
+            pivots,
+            transform,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{row_reduce, null_space};
+    use crate::field::{IntMod2, Rational};
+    use crate::vector_space::{Matrix, VectorSpace};
+    use crate::matrix;
+
+    fn rows<const M: usize, const N: usize>(arr: [[i64; N]; M]) -> Vec<Vec<Rational>> {
+        arr.iter().map(|row| row.iter().map(|&x| Rational::from(x)).collect()).collect()
+    }
+
+    #[test]
+    fn echelon_row_reduce() {
+        let mut m = rows([[3, 2, 3, -2], [1, 1, 1, 0], [1, 2, 1, -1]]);
+        let pivots = row_reduce(&mut m);
+        assert_eq!(pivots, vec![0, 1, 3]);
+        assert_eq!(m, rows([[1, 0, 1, 0], [0, 1, 0, 0], [0, 0, 0, 1]]));
+    }
+
+    #[test]
+    fn echelon_null_space() {
+        let mut m = rows([[1, 2, 1], [2, 4, 2]]);
+        let pivots = row_reduce(&mut m);
+        assert_eq!(null_space(&m, &pivots, 3), rows([[-2, 1, 0], [-1, 0, 1]]));
+    }
+
+    #[test]
+    fn matrix_rref() {
+        let a = matrix![[1, 2, 3, 1], [2, 4, 6, 2], [-1, -2, -1, 1]];
+        let rref = a.rref();
+        assert_eq!(rref.reduced, matrix![[1, 2, 0, -2], [0, 0, 1, 1], [0, 0, 0, 0]]);
+        assert_eq!(rref.pivots, vec![0, 2]);
+        assert_eq!(rref.rank, 2);
+        assert_eq!(rref.transform * a, rref.reduced);
+        assert!(rref.transform.inverse().is_some());
+
+        let o = IntMod2::Zero;
+        let i = IntMod2::One;
+        let b = matrix![[o, i, i], [i, i, o], [i, o, i]];
+        let rref = b.rref();
+        assert_eq!(rref.reduced, matrix![[i, o, i], [o, i, i], [o, o, o]]);
+        assert_eq!(rref.transform * b, rref.reduced);
+    }
+
+    #[test]
+    fn matrix_rref_partial_pivoting() {
+        // Pivoting on the tiny entry would lose the second row to rounding.
+        let a = matrix![[1e-20, 1.0], [1.0, 1.0]];
+        let rref = a.rref();
+        assert_eq!(rref.reduced, Matrix::identity());
+        assert_eq!(rref.pivots, vec![0, 1]);
+
+        // Rounding error in a dependent row is treated as zero.
+        let b = matrix![[0.1, 0.2], [0.3, 0.6000000000000001]];
+        let rref = b.rref();
+        assert_eq!(rref.rank, 1);
+        assert!((rref.reduced[(0, 1)] - 2.0).abs() < 1e-12);
+        assert_eq!(rref.reduced[1], [0.0, 0.0]);
+
+        let c = matrix![[1e-20, 2e-20], [3e-20, 4e-20]];
+        assert_eq!(c.rref().rank, 2);
+        assert_eq!(<Matrix<f64, 2, 2> as VectorSpace<f64>>::zero().rref().rank, 0);
+    }
+}
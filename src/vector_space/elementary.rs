@@ -0,0 +1,269 @@
+use crate::field::Field;
+use super::Matrix;
+use std::fmt;
+
+/// An elementary operation on the rows or columns of a matrix (3.1). Rows and columns are indexed from 0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ElementaryOperation<F: Field> {
+    /// Type 1: interchanging rows or columns i and j.
+    Swap(usize, usize),
+    /// Type 2: multiplying row or column i by a nonzero scalar c.
+    Scale(usize, F),
+    /// Type 3: adding c times row or column j to row or column i, where i ≠ j.
+    AddMultiple(usize, usize, F),
+}
+
+/// Whether an elementary operation is performed on rows or on columns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Row,
+    Column,
+}
+
+impl<F: Field> ElementaryOperation<F> {
+    /// Returns the elementary operation undoing this one, which is of the same type (3.2).
+    pub fn inverse(&self) -> Self {
+        match *self {
+            Self::Swap(i, j) => Self::Swap(i, j),
+            Self::Scale(i, c) => Self::Scale(i, F::one() / c),
+            Self::AddMultiple(i, j, c) => Self::AddMultiple(i, j, -c),
+        }
+    }
+
+    /// Returns the N × N elementary matrix E obtained by performing this operation on the rows of I_N.
+    /// Performing the operation on the rows of an N × K matrix A gives EA (3.1).
+    pub fn row_matrix<const N: usize>(&self) -> Matrix<F, N, N> {
+        let mut e = Matrix::identity();
+        e.row_operation(*self);
+        e
+    }
+
+    /// Returns the N × N elementary matrix E obtained by performing this operation on the columns of I_N.
+    /// Performing the operation on the columns of a K × N matrix A gives AE (3.1).
+    pub fn column_matrix<const N: usize>(&self) -> Matrix<F, N, N> {
+        let mut e = Matrix::identity();
+        e.column_operation(*self);
+        e
+    }
+}
+
+impl<F: Field, const M: usize, const N: usize> Matrix<F, M, N> {
+    /// Interchanges rows i and j.
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        for k in 0..N {
+            let entry = self[(i, k)];
+            self[(i, k)] = self[(j, k)];
+            self[(j, k)] = entry;
+        }
+    }
+
+    /// Multiplies row i by the nonzero scalar c. Panics if c is zero.
+    pub fn scale_row(&mut self, i: usize, c: F) {
+        assert!(c != F::zero(), "an elementary operation cannot scale by zero");
+        for k in 0..N {
+            self[(i, k)] *= c;
+        }
+    }
+
+    /// Adds c times row j to row i. Panics if i = j.
+    pub fn add_row_multiple(&mut self, i: usize, j: usize, c: F) {
+        assert_ne!(i, j, "an elementary operation cannot add a multiple of a row to itself");
+        for k in 0..N {
+            let entry = self[(j, k)];
+            self[(i, k)] += c * entry;
+        }
+    }
+
+    /// Interchanges columns i and j.
+    pub fn swap_columns(&mut self, i: usize, j: usize) {
+        for k in 0..M {
+            let entry = self[(k, i)];
+            self[(k, i)] = self[(k, j)];
+            self[(k, j)] = entry;
+        }
+    }
+
+    /// Multiplies column i by the nonzero scalar c. Panics if c is zero.
+    pub fn scale_column(&mut self, i: usize, c: F) {
+        assert!(c != F::zero(), "an elementary operation cannot scale by zero");
+        for k in 0..M {
+            self[(k, i)] *= c;
+        }
+    }
+
+    /// Adds c times column j to column i. Panics if i = j.
+    pub fn add_column_multiple(&mut self, i: usize, j: usize, c: F) {
+        assert_ne!(i, j, "an elementary operation cannot add a multiple of a column to itself");
+        for k in 0..M {
+            let entry = self[(k, j)];
+            self[(k, i)] += c * entry;
+        }
+    }
+
+    /// Performs the given elementary operation on the rows of the matrix.
+    pub fn row_operation(&mut self, op: ElementaryOperation<F>) {
+        match op {
+            ElementaryOperation::Swap(i, j) => self.swap_rows(i, j),
+            ElementaryOperation::Scale(i, c) => self.scale_row(i, c),
+            ElementaryOperation::AddMultiple(i, j, c) => self.add_row_multiple(i, j, c),
+        }
+    }
+
+    /// Performs the given elementary operation on the columns of the matrix.
+    pub fn column_operation(&mut self, op: ElementaryOperation<F>) {
+        match op {
+            ElementaryOperation::Swap(i, j) => self.swap_columns(i, j),
+            ElementaryOperation::Scale(i, c) => self.scale_column(i, c),
+            ElementaryOperation::AddMultiple(i, j, c) => self.add_column_multiple(i, j, c),
+        }
+    }
+}
+
+/// A record of the elementary operations performed on a matrix, in the order they were performed.
+/// If row operations with elementary matrices E_1, ..., E_k and column operations with elementary
+/// matrices G_1, ..., G_l transform A into B, then B = E_k ⋯ E_1 A G_1 ⋯ G_l.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OperationLog<F: Field> {
+    steps: Vec<(Side, ElementaryOperation<F>)>,
+}
+
+impl<F: Field> OperationLog<F> {
+    /// Creates an empty log.
+    pub fn new() -> Self {
+        Self {
+            steps: vec![]
+        }
+    }
+
+    /// Performs the given operation on the rows of A and records it.
+    pub fn row_operation<const M: usize, const N: usize>(&mut self, a: &mut Matrix<F, M, N>, op: ElementaryOperation<F>) {
+        a.row_operation(op);
+        self.steps.push((Side::Row, op));
+    }
+
+    /// Performs the given operation on the columns of A and records it.
+    pub fn column_operation<const M: usize, const N: usize>(&mut self, a: &mut Matrix<F, M, N>, op: ElementaryOperation<F>) {
+        a.column_operation(op);
+        self.steps.push((Side::Column, op));
+    }
+
+    /// Returns the recorded operations, in order.
+    pub fn steps(&self) -> &[(Side, ElementaryOperation<F>)] {
+        &self.steps
+    }
+
+    /// Returns the number of recorded operations.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns whether no operations have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Returns the product E_k ⋯ E_1 of the elementary matrices of the row operations.
+    pub fn row_matrix<const M: usize>(&self) -> Matrix<F, M, M> {
+        let mut out = Matrix::identity();
+        for (_, op) in self.steps.iter().filter(|(side, _)| *side == Side::Row) {
+            out.row_operation(*op);
+        }
+        out
+    }
+
+    /// Returns the product G_1 ⋯ G_l of the elementary matrices of the column operations.
+    pub fn column_matrix<const N: usize>(&self) -> Matrix<F, N, N> {
+        let mut out = Matrix::identity();
+        for (_, op) in self.steps.iter().filter(|(side, _)| *side == Side::Column) {
+            out.column_operation(*op);
+        }
+        out
+    }
+
+    /// Returns the log of the inverse operations in reverse order, which undoes this one.
+    pub fn inverse(&self) -> Self {
+        Self {
+            steps: self.steps.iter().rev().map(|(side, op)| (*side, op.inverse())).collect()
+        }
+    }
+}
+
+impl<F: Field> Default for OperationLog<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Operations are displayed as in the text, numbering rows R1, R2, ... and columns C1, C2, ...
+impl<F: Field> fmt::Display for OperationLog<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (side, op) in self.steps.iter() {
+            let name = match side {
+                Side::Row => "R",
+                Side::Column => "C",
+            };
+            match *op {
+                ElementaryOperation::Swap(i, j) => writeln!(f, "{}{} <-> {}{}", name, i + 1, name, j + 1)?,
+                ElementaryOperation::Scale(i, c) => writeln!(f, "{}{} -> ({}){}{}", name, i + 1, c, name, i + 1)?,
+                ElementaryOperation::AddMultiple(i, j, c) => writeln!(f, "{}{} -> {}{} + ({}){}{}", name, i + 1, name, i + 1, c, name, j + 1)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ElementaryOperation, OperationLog};
+    use crate::field::Rational;
+    use crate::vector_space::Matrix;
+    use crate::matrix;
+
+    #[test]
+    fn elementary_matrices() {
+        // 3.1 Example 1
+        let a = matrix![[1, 2, 3, 4], [2, 1, -1, 3], [4, 0, 1, 2]];
+        let swap = ElementaryOperation::Swap(0, 1);
+        let mut b = a;
+        b.row_operation(swap);
+        assert_eq!(b, matrix![[2, 1, -1, 3], [1, 2, 3, 4], [4, 0, 1, 2]]);
+        assert_eq!(swap.row_matrix() * a, b);
+
+        let scale = ElementaryOperation::Scale(1, Rational::from(3));
+        let mut c = a;
+        c.column_operation(scale);
+        assert_eq!(c, matrix![[1, 6, 3, 4], [2, 3, -1, 3], [4, 0, 1, 2]]);
+        assert_eq!(a * scale.column_matrix(), c);
+
+        let add = ElementaryOperation::AddMultiple(2, 0, Rational::from(-4));
+        let mut d = a;
+        d.row_operation(add);
+        assert_eq!(d, matrix![[1, 2, 3, 4], [2, 1, -1, 3], [0, -8, -11, -14]]);
+        assert_eq!(add.row_matrix() * a, d);
+        assert_eq!(add.row_matrix(), matrix![[1, 0, 0], [0, 1, 0], [-4, 0, 1]]);
+        assert_eq!(add.column_matrix(), matrix![[1, 0, -4], [0, 1, 0], [0, 0, 1]]);
+
+        for op in [swap, scale, add].iter() {
+            let e: Matrix<Rational, 3, 3> = op.row_matrix();
+            assert_eq!(e * op.inverse().row_matrix(), Matrix::identity());
+        }
+    }
+
+    #[test]
+    fn operation_log() {
+        let mut a = matrix![[0, 2, 4], [1, 1, 1]];
+        let original = a;
+        let mut log = OperationLog::new();
+        log.row_operation(&mut a, ElementaryOperation::Swap(0, 1));
+        log.row_operation(&mut a, ElementaryOperation::Scale(1, Rational::from(1) / Rational::from(2)));
+        log.column_operation(&mut a, ElementaryOperation::AddMultiple(2, 0, Rational::from(-1)));
+        log.row_operation(&mut a, ElementaryOperation::AddMultiple(0, 1, Rational::from(-1)));
+        assert_eq!(a, matrix![[1, 0, -2], [0, 1, 2]]);
+        assert_eq!(log.len(), 4);
+        assert_eq!(log.row_matrix() * original * log.column_matrix(), a);
+
+        let inverse = log.inverse();
+        assert_eq!(inverse.row_matrix() * a * inverse.column_matrix(), original);
+        assert_eq!(log.to_string(), "R1 <-> R2\nR2 -> (1/2)R2\nC3 -> C3 + (-1)C1\nR1 -> R1 + (-1)R2\n");
+    }
+}
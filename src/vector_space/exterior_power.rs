@@ -0,0 +1,287 @@
+use crate::field::{ConjugateField, Field, Real};
+use crate::norm::Normed;
+use super::{VectorSpace, Entries, FiniteDimensional, Tuple};
+use std::ops::{Add, Mul, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssign, Neg};
+use std::fmt;
+
+/// A type representing a K-vector in the exterior power Λᴷ(F^N).
+/// The standard ordered basis is made up of the wedge products e_S = e_i1 ∧ ... ∧ e_iK for
+/// increasing indices i1 < ... < iK, with the index sets S ordered lexicographically,
+/// so that dim Λᴷ(F^N) = C(N, K). The K-vector is stored as its coefficients relative to this basis.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ExteriorPower<F: Field, const N: usize, const K: usize>(Vec<F>);
+
+impl<F: Field, const N: usize, const K: usize> ExteriorPower<F, N, K> {
+    /// Returns the wedge product v_1 ∧ ... ∧ v_K, whose coefficient on e_S is the K × K minor of
+    /// the matrix with columns v_1, ..., v_K taken from the rows in S.
+    /// This is zero exactly when the vectors are linearly dependent.
+    pub fn from_vectors(vectors: &[Tuple<F, N>; K]) -> Self {
+        Self::from_slice(vectors)
+    }
+
+    /// Returns the wedge product of a list of exactly K vectors.
+    pub(crate) fn from_slice(vectors: &[Tuple<F, N>]) -> Self {
+        assert_eq!(vectors.len(), K);
+        let mut accum = vec![F::one()];
+        for (k, v) in vectors.iter().enumerate() {
+            accum = wedge_coefficients(&accum, k, &v.entries(), 1, N);
+        }
+        Self(accum)
+    }
+
+    /// Returns the coefficient on e_i1 ∧ ... ∧ e_iK, for strictly increasing indices.
+    pub fn coefficient(&self, indices: &[usize; K]) -> F {
+        let position = subsets(N, K).iter().position(|s| s[..] == indices[..])
+            .expect("indices of a basis K-vector must be strictly increasing and less than N");
+        self.0[position]
+    }
+
+    /// Returns the wedge product ω ∧ η of a K-vector and an L-vector.
+    /// The output must have `S = K + L`, and satisfies ω ∧ η = (-1)^(KL) η ∧ ω.
+    pub fn wedge<const L: usize, const S: usize>(&self, other: &ExteriorPower<F, N, L>) -> ExteriorPower<F, N, S> {
+        assert_eq!(S, K + L, "the wedge product of a {}-vector and a {}-vector is a {}-vector", K, L, K + L);
+        ExteriorPower(wedge_coefficients(&self.0, K, &other.0, L, N))
+    }
+}
+
+impl<F: Field, const N: usize> From<Tuple<F, N>> for ExteriorPower<F, N, 1> {
+    fn from(v: Tuple<F, N>) -> Self {
+        Self(v.entries())
+    }
+}
+
+/// Returns the determinant det(v_1, ..., v_N), which is the unique N-linear form on F^N that is
+/// alternating and has det(e_1, ..., e_N) = 1. It is the coefficient of v_1 ∧ ... ∧ v_N on
+/// e_1 ∧ ... ∧ e_N, since Λᴺ(F^N) is one-dimensional.
+pub fn determinant<F: Field, const N: usize>(vectors: &[Tuple<F, N>; N]) -> F {
+    ExteriorPower::<F, N, N>::from_vectors(vectors).0[0]
+}
+
+/// Returns the K-element subsets of {0, ..., N - 1} as increasing lists, in lexicographic order.
+pub(crate) fn subsets(n: usize, k: usize) -> Vec<Vec<usize>> {
+    if k == 0 {
+        return vec![vec![]];
+    }
+    let mut out = Vec::new();
+    for last in k-1..n {
+        for mut s in subsets(last, k - 1) {
+            s.push(last);
+            out.push(s);
+        }
+    }
+    out.sort();
+    out
+}
+
+/// Returns the coefficients of ω ∧ η for a K-vector ω and an L-vector η in Λ(F^N).
+/// Each e_A ∧ e_B is zero if A and B meet, and otherwise is ±e_(A ∪ B), with the sign of the
+/// permutation sorting A followed by B.
+fn wedge_coefficients<F: Field>(a: &[F], k: usize, b: &[F], l: usize, n: usize) -> Vec<F> {
+    let (left, right, out) = (subsets(n, k), subsets(n, l), subsets(n, k + l));
+    let mut coefficients = vec![F::zero(); out.len()];
+    for (s, x) in left.iter().zip(a) {
+        for (t, y) in right.iter().zip(b) {
+            if *x == F::zero() || *y == F::zero() || s.iter().any(|i| t.contains(i)) {
+                continue;
+            }
+            let inversions: usize = s.iter().map(|i| t.iter().filter(|j| j < &i).count()).sum();
+            let mut union: Vec<usize> = s.iter().chain(t.iter()).copied().collect();
+            union.sort();
+            let position = out.iter().position(|u| *u == union).unwrap();
+            if inversions % 2 == 1 {
+                coefficients[position] -= *x * *y;
+            } else {
+                coefficients[position] += *x * *y;
+            }
+        }
+    }
+    coefficients
+}
+
+// Operator implementations for K-vectors with K-vectors.
+impl<F: Field, const N: usize, const K: usize> Neg for ExteriorPower<F, N, K> {
+    type Output = Self;
+    fn neg(mut self) -> Self {
+        for c in self.0.iter_mut() {
+            *c = -*c;
+        }
+        self
+    }
+}
+impl<F: Field, const N: usize, const K: usize> Add<&Self> for ExteriorPower<F, N, K> {
+    type Output = Self;
+    fn add(mut self, rhs: &Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl<F: Field, const N: usize, const K: usize> Add<Self> for ExteriorPower<F, N, K> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl<F: Field, const N: usize, const K: usize> Sub<&Self> for ExteriorPower<F, N, K> {
+    type Output = Self;
+    fn sub(mut self, rhs: &Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl<F: Field, const N: usize, const K: usize> Sub<Self> for ExteriorPower<F, N, K> {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl<F: Field, const N: usize, const K: usize> AddAssign<&Self> for ExteriorPower<F, N, K> {
+    fn add_assign(&mut self, rhs: &Self) {
+        for i in 0..self.0.len() {
+            self.0[i] += rhs.0[i];
+        }
+    }
+}
+impl<F: Field, const N: usize, const K: usize> AddAssign<Self> for ExteriorPower<F, N, K> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+impl<F: Field, const N: usize, const K: usize> SubAssign<&Self> for ExteriorPower<F, N, K> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        for i in 0..self.0.len() {
+            self.0[i] -= rhs.0[i];
+        }
+    }
+}
+impl<F: Field, const N: usize, const K: usize> SubAssign<Self> for ExteriorPower<F, N, K> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+// Operator implementations for K-vectors with scalars.
+impl<T: Into<F>, F: Field, const N: usize, const K: usize> Mul<T> for ExteriorPower<F, N, K> {
+    type Output = Self;
+    fn mul(mut self, rhs: T) -> Self {
+        self *= rhs;
+        self
+    }
+}
+impl<T: Into<F>, F: Field, const N: usize, const K: usize> Div<T> for ExteriorPower<F, N, K> {
+    type Output = Self;
+    fn div(mut self, rhs: T) -> Self {
+        self /= rhs;
+        self
+    }
+}
+impl<T: Into<F>, F: Field, const N: usize, const K: usize> MulAssign<T> for ExteriorPower<F, N, K> {
+    fn mul_assign(&mut self, rhs: T) {
+        let scale = rhs.into();
+        for c in self.0.iter_mut() {
+            *c *= scale;
+        }
+    }
+}
+impl<T: Into<F>, F: Field, const N: usize, const K: usize> DivAssign<T> for ExteriorPower<F, N, K> {
+    fn div_assign(&mut self, rhs: T) {
+        let scale = rhs.into();
+        for c in self.0.iter_mut() {
+            *c /= scale;
+        }
+    }
+}
+
+impl<F: Field, const N: usize, const K: usize> fmt::Display for ExteriorPower<F, N, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, c) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", c)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<F: Field, const N: usize, const K: usize> Entries<F> for ExteriorPower<F, N, K> {
+    fn entries(&self) -> Vec<F> {
+        self.0.clone()
+    }
+}
+
+impl<F: Field, const N: usize, const K: usize> VectorSpace<F> for ExteriorPower<F, N, K> {
+    fn zero() -> Self {
+        Self(vec![F::zero(); subsets(N, K).len()])
+    }
+}
+
+impl<F: Field, const N: usize, const K: usize> FiniteDimensional<F> for ExteriorPower<F, N, K> {
+    fn dim() -> usize {
+        subsets(N, K).len()
+    }
+
+    fn from_entries(entries: &[F]) -> Self {
+        Self(entries.to_vec())
+    }
+}
+
+// The norm making the e_S orthonormal. The norm of v_1 ∧ ... ∧ v_K is the K-dimensional volume of the
+// parallelotope spanned by the v_i, which is sqrt(det(AᵗA)) for A with columns v_i (Cauchy-Binet).
+impl<F: ConjugateField, const N: usize, const K: usize> Normed for ExteriorPower<F, N, K> {
+    fn norm(&self) -> Real {
+        self.0.iter().map(|c| c.modulus().powi(2)).sum::<Real>().sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExteriorPower, determinant, subsets};
+    use crate::field::Rational;
+    use crate::norm::Normed;
+    use crate::vector_space::{FiniteDimensional, Tuple, VectorSpace};
+    use crate::tuple;
+
+    #[test]
+    fn exterior_power_basis() {
+        assert_eq!(subsets(4, 2), vec![vec![0, 1], vec![0, 2], vec![0, 3], vec![1, 2], vec![1, 3], vec![2, 3]]);
+        assert_eq!(<ExteriorPower<Rational, 5, 3> as FiniteDimensional<Rational>>::dim(), 10);
+        assert_eq!(<ExteriorPower<Rational, 3, 0> as FiniteDimensional<Rational>>::dim(), 1);
+    }
+
+    #[test]
+    fn exterior_power_wedge() {
+        let u = tuple![1, 2, 3];
+        let v = tuple![4, 5, 6];
+        let w = tuple![0, 1, 1];
+        let uv = ExteriorPower::from_vectors(&[u.clone(), v.clone()]);
+        assert_eq!(uv, ExteriorPower::from_entries(&[Rational::from(-3), Rational::from(-6), Rational::from(-3)]));
+        assert_eq!(uv.coefficient(&[0, 2]), Rational::from(-6));
+        assert_eq!(ExteriorPower::from_vectors(&[v.clone(), u.clone()]), -uv.clone());
+        assert_eq!(ExteriorPower::from_vectors(&[u.clone(), u.clone()]), ExteriorPower::zero());
+
+        let (eu, ev, ew) = (ExteriorPower::from(u), ExteriorPower::from(v), ExteriorPower::from(w));
+        let left: ExteriorPower<Rational, 3, 3> = uv.wedge(&ew);
+        let vw: ExteriorPower<Rational, 3, 2> = ev.wedge(&ew);
+        assert_eq!(left, eu.wedge(&vw));
+    }
+
+    #[test]
+    fn exterior_power_determinant() {
+        let e: [Tuple<Rational, 3>; 3] = [tuple![1, 0, 0], tuple![0, 1, 0], tuple![0, 0, 1]];
+        assert_eq!(determinant(&e), Rational::from(1));
+        assert_eq!(determinant(&[tuple![1, 3], tuple![2, 4]]), Rational::from(-2));
+        assert_eq!(determinant(&[tuple![1, 3], tuple![2, 6]]), Rational::from(0));
+        assert_eq!(determinant(&[e[1].clone(), e[0].clone(), e[2].clone()]), Rational::from(-1));
+    }
+
+    #[test]
+    fn exterior_power_volume() {
+        assert_eq!(ExteriorPower::from_vectors(&[tuple![1.0, 0.0, 0.0], tuple![1.0, 1.0, 0.0]]).norm(), 1.0);
+        assert_eq!(ExteriorPower::from_vectors(&[tuple![1.0, 2.0, 3.0], tuple![4.0, 5.0, 6.0]]).norm(), 54.0f64.sqrt());
+        let cube = [tuple![2.0, 0.0, 0.0, 0.0], tuple![0.0, 2.0, 0.0, 0.0], tuple![0.0, 0.0, 2.0, 0.0]];
+        assert_eq!(ExteriorPower::from_vectors(&cube).norm(), 8.0);
+    }
+}
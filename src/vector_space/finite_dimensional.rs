@@ -0,0 +1,108 @@
+use crate::field::Field;
+use super::{VectorSpace, Entries, Tuple};
+use super::echelon::row_reduce;
+use super::independence::is_linearly_independent;
+
+/// A trait for vector spaces of finite dimension, equipped with a standard ordered basis.
+/// The entries of a vector must be its coordinates relative to the standard basis, and so
+/// there must always be exactly `dim()` of them.
+///
+/// An ordered basis β = {v_1, ..., v_D} is represented as an array `[Self; D]`,
+/// and [x]_β is returned as a `Tuple<F, D>`.
+pub trait FiniteDimensional<F: Field>: VectorSpace<F> + Entries<F> + Clone {
+    /// Returns the dimension of the vector space.
+    fn dim() -> usize;
+
+    /// Returns the vector with the given coordinates relative to the standard ordered basis.
+    /// Assumes that exactly `dim()` entries are given.
+    fn from_entries(entries: &[F]) -> Self;
+
+    /// Returns the standard ordered basis.
+    fn standard_basis() -> Vec<Self> {
+        (0..Self::dim()).map(|i| {
+            let mut entries = vec![F::zero(); Self::dim()];
+            entries[i] = F::one();
+            Self::from_entries(&entries)
+        }).collect()
+    }
+
+    /// Returns whether the given list of vectors is an ordered basis for the space.
+    fn is_basis(vectors: &[Self]) -> bool {
+        vectors.len() == Self::dim() && is_linearly_independent(vectors)
+    }
+
+    /// Returns the coordinate vector [x]_β of this vector relative to the ordered basis β.
+    /// Assumes that β is a basis.
+    fn coordinates<const D: usize>(&self, basis: &[Self; D]) -> Tuple<F, D> {
+        // Row reduce the augmented matrix whose columns are the basis vectors followed by x.
+        let columns: Vec<Vec<F>> = basis.iter().chain(Some(self)).map(|v| v.entries()).collect();
+        let mut rows: Vec<Vec<F>> = (0..Self::dim())
+            .map(|i| columns.iter().map(|c| c[i]).collect())
+            .collect();
+        row_reduce(&mut rows);
+        let mut coordinates = Tuple::<F, D>::zero();
+        for j in 0..D {
+            coordinates[j] = rows[j][D];
+        }
+        coordinates
+    }
+
+    /// Returns the vector x with [x]_β equal to the given coordinates, which is the inverse of the
+    /// standard representation φ_β.
+    fn from_coordinates<const D: usize>(coordinates: &Tuple<F, D>, basis: &[Self; D]) -> Self {
+        let mut x = Self::zero();
+        for j in 0..D {
+            x += basis[j].clone() * coordinates[j];
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FiniteDimensional;
+    use crate::field::{IntMod2, Rational};
+    use crate::vector_space::{BoundedPolynom, Matrix, Tuple, Zero};
+    use crate::{tuple, polynom, matrix};
+
+    #[test]
+    fn finite_dimensional_standard_basis() {
+        assert_eq!(Tuple::<Rational, 3>::standard_basis(), vec![tuple![1, 0, 0], tuple![0, 1, 0], tuple![0, 0, 1]]);
+        assert_eq!(Matrix::<Rational, 2, 2>::standard_basis()[1], matrix![[0, 1], [0, 0]]);
+        assert_eq!(<BoundedPolynom<Rational, 2> as FiniteDimensional<Rational>>::dim(), 3);
+        assert_eq!(<Zero as FiniteDimensional<Rational>>::standard_basis(), vec![]);
+    }
+
+    #[test]
+    fn finite_dimensional_tuple_coordinates() {
+        let beta = [tuple![1, 1], tuple![1, -1]];
+        let x = tuple![3, 1];
+        assert_eq!(x.coordinates(&beta), tuple![2, 1]);
+        assert_eq!(Tuple::from_coordinates(&tuple![2, 1], &beta), x);
+
+        let (o, i) = (IntMod2::Zero, IntMod2::One);
+        let beta = [tuple![i, i, o], tuple![o, i, i], tuple![o, o, i]];
+        assert!(Tuple::is_basis(&beta));
+        assert_eq!(tuple![i, o, o].coordinates(&beta), tuple![i, i, i]);
+    }
+
+    #[test]
+    fn finite_dimensional_polynom_coordinates() {
+        let beta: [BoundedPolynom<Rational, 2>; 3] = [
+            BoundedPolynom::new(polynom![1]).unwrap(),
+            BoundedPolynom::new(polynom![1, 1]).unwrap(),
+            BoundedPolynom::new(polynom![1, 1, 1]).unwrap(),
+        ];
+        assert!(BoundedPolynom::is_basis(&beta));
+        let f = BoundedPolynom::new(polynom![4, 3, 2]).unwrap();
+        assert_eq!(f.coordinates(&beta), tuple![1, 1, 2]);
+        assert_eq!(BoundedPolynom::from_coordinates(&tuple![1, 1, 2], &beta), f);
+        assert!(BoundedPolynom::<Rational, 2>::new(polynom![0, 0, 0, 1]).is_none());
+    }
+
+    #[test]
+    fn finite_dimensional_matrix_coordinates() {
+        let beta = [matrix![[1, 0], [0, 0]], matrix![[1, 1], [0, 0]], matrix![[1, 1], [1, 0]], matrix![[1, 1], [1, 1]]];
+        assert_eq!(matrix![[4, 3], [2, 1]].coordinates(&beta), tuple![1, 1, 1, 1]);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::field::FiniteField;
+use super::{Tuple, Subspace, VectorSpace, subsets};
+
+impl<F: FiniteField, const N: usize> Tuple<F, N> {
+    /// Returns an iterator over all q^N vectors of F^N, where q is the order of F.
+    pub fn all() -> impl Iterator<Item=Self> {
+        let elements = F::elements();
+        (0..Self::count()).map(move |m| digits(&elements, m, N).into_iter().collect())
+    }
+
+    /// Returns the number q^N of vectors in F^N.
+    pub fn count() -> usize {
+        F::order().pow(N as u32)
+    }
+}
+
+impl<F: FiniteField, const N: usize> Subspace<F, N> {
+    /// Returns an iterator over all k-dimensional subspaces of F^N.
+    /// Each subspace has exactly one basis in reduced row echelon form, so the subspaces are found by
+    /// choosing the k pivot columns and then every entry right of a pivot outside the pivot columns.
+    pub fn all(k: usize) -> impl Iterator<Item=Self> {
+        let elements = F::elements();
+        subsets(N, k).into_iter().flat_map(move |pivots| {
+            let free: Vec<(usize, usize)> = pivots.iter().enumerate()
+                .flat_map(|(i, &p)| (p+1..N).filter(|j| !pivots.contains(j)).map(move |j| (i, j)))
+                .collect();
+            let elements = elements.clone();
+            (0..elements.len().pow(free.len() as u32)).map(move |m| {
+                let mut basis: Vec<Tuple<F, N>> = pivots.iter().map(|&p| {
+                    let mut e = Tuple::zero();
+                    e[p] = F::one();
+                    e
+                }).collect();
+                for (&(i, j), a) in free.iter().zip(digits(&elements, m, free.len())) {
+                    basis[i][j] = a;
+                }
+                Self::span(&basis)
+            })
+        })
+    }
+
+    /// Returns the number of k-dimensional subspaces of F^N, which is the Gaussian binomial coefficient
+    /// of N and k at the order of F.
+    pub fn count(k: usize) -> usize {
+        gaussian_binomial(N, k, F::order())
+    }
+}
+
+/// Returns the Gaussian binomial coefficient [n k]_q = ∏ (q^(n-i) - 1) / (q^(i+1) - 1) over 0 ≤ i < k,
+/// which counts the k-dimensional subspaces of an n-dimensional vector space over a field with q elements.
+pub fn gaussian_binomial(n: usize, k: usize, q: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+    // Each partial product is itself [n i+1]_q, so every division is exact.
+    let mut out = 1;
+    for i in 0..k {
+        out = out * (q.pow((n - i) as u32) - 1) / (q.pow(i as u32 + 1) - 1);
+    }
+    out
+}
+
+/// Returns the first `len` digits of m in base q as field elements, least significant first.
+fn digits<F: FiniteField>(elements: &[F], mut m: usize, len: usize) -> Vec<F> {
+    let q = elements.len();
+    (0..len).map(|_| {
+        let digit = elements[m % q];
+        m /= q;
+        digit
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gaussian_binomial;
+    use crate::field::IntMod2;
+    use crate::vector_space::{Tuple, Subspace};
+
+    #[test]
+    fn enumerate_vectors() {
+        let all: Vec<Tuple<IntMod2, 3>> = Tuple::all().collect();
+        assert_eq!(all.len(), Tuple::<IntMod2, 3>::count());
+        assert_eq!(all.len(), 8);
+        for (i, x) in all.iter().enumerate() {
+            assert!(!all[..i].contains(x));
+        }
+    }
+
+    #[test]
+    fn enumerate_subspaces() {
+        // (Z_2)^4 has [4 2]_2 = 35 subspaces of dimension 2, each containing 4 vectors.
+        let planes: Vec<Subspace<IntMod2, 4>> = Subspace::all(2).collect();
+        assert_eq!(planes.len(), 35);
+        assert_eq!(Subspace::<IntMod2, 4>::count(2), 35);
+        for (i, w) in planes.iter().enumerate() {
+            assert_eq!(w.dim(), 2);
+            assert!(!planes[..i].contains(w));
+            assert_eq!(Tuple::all().filter(|x| w.contains(x)).count(), 4);
+        }
+        for k in 0..=4 {
+            assert_eq!(Subspace::<IntMod2, 4>::all(k).count(), Subspace::<IntMod2, 4>::count(k));
+        }
+        assert_eq!(Subspace::<IntMod2, 3>::all(4).count(), 0);
+    }
+
+    #[test]
+    fn gaussian_binomial_values() {
+        assert_eq!(gaussian_binomial(3, 1, 2), 7);
+        assert_eq!(gaussian_binomial(4, 2, 3), 130);
+        assert_eq!(gaussian_binomial(5, 0, 7), 1);
+        assert_eq!(gaussian_binomial(5, 5, 7), 1);
+        assert_eq!(gaussian_binomial(6, 2, 2), gaussian_binomial(6, 4, 2));
+    }
+}
@@ -0,0 +1,156 @@
+use crate::field::Field;
+use super::{Matrix, Tuple, Rref, FiniteDimensional};
+use super::echelon::null_space;
+use std::fmt;
+
+/// Bases for the four fundamental subspaces of an M × N matrix A, as returned by `Matrix::fundamental_subspaces`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FundamentalSubspaces<F: Field, const M: usize, const N: usize> {
+    /// The rank of A, which is the dimension of both its row space and its column space.
+    pub rank: usize,
+    /// A basis for the null space N(A) = {x in F^N : Ax = 0}.
+    pub null_space: Vec<Tuple<F, N>>,
+    /// A basis for the column space R(A) = {Ax : x in F^N} in F^M.
+    pub column_space: Vec<Tuple<F, M>>,
+    /// A basis for the row space R(Aᵗ) in F^N.
+    pub row_space: Vec<Tuple<F, N>>,
+    /// A basis for the left null space N(Aᵗ) = {y in F^M : yᵗA = 0}.
+    pub left_null_space: Vec<Tuple<F, M>>,
+}
+
+impl<F: Field, const M: usize, const N: usize> Matrix<F, M, N> {
+    /// Returns the rank of the matrix.
+    pub fn rank(&self) -> usize {
+        self.rref().rank
+    }
+
+    /// Returns the nullity of the matrix, which is N - rank(A) by the dimension theorem (2.3).
+    pub fn nullity(&self) -> usize {
+        N - self.rank()
+    }
+
+    /// Returns a basis for the null space N(A).
+    pub fn null_space(&self) -> Vec<Tuple<F, N>> {
+        self.null_space_basis(&self.rref())
+    }
+
+    /// Returns a basis for the column space R(A).
+    pub fn column_space(&self) -> Vec<Tuple<F, M>> {
+        self.column_space_basis(&self.rref())
+    }
+
+    /// Returns a basis for the row space of A.
+    pub fn row_space(&self) -> Vec<Tuple<F, N>> {
+        self.row_space_basis(&self.rref())
+    }
+
+    /// Returns a basis for the left null space N(Aᵗ).
+    pub fn left_null_space(&self) -> Vec<Tuple<F, M>> {
+        self.left_null_space_basis(&self.rref())
+    }
+
+    /// Returns bases for the null space, column space, row space and left null space of the matrix,
+    /// all read off from a single row reduction PA = R:
+    /// - N(A) has one vector for each non-pivot column of R;
+    /// - R(A) is spanned by the columns of A in the pivot columns of R (3.16);
+    /// - the row space is spanned by the nonzero rows of R;
+    /// - N(Aᵗ) is spanned by the rows of P matching the zero rows of R, which are independent since P is invertible.
+    pub fn fundamental_subspaces(&self) -> FundamentalSubspaces<F, M, N> {
+        let rref = self.rref();
+        FundamentalSubspaces {
+            rank: rref.rank,
+            null_space: self.null_space_basis(&rref),
+            column_space: self.column_space_basis(&rref),
+            row_space: self.row_space_basis(&rref),
+            left_null_space: self.left_null_space_basis(&rref),
+        }
+    }
+
+    fn null_space_basis(&self, rref: &Rref<F, M, N>) -> Vec<Tuple<F, N>> {
+        let rows: Vec<Vec<F>> = rref.reduced.rows().map(|row| row.iter().copied().collect()).collect();
+        null_space(&rows, &rref.pivots, N).iter().map(|x| Tuple::from_entries(x)).collect()
+    }
+
+    fn column_space_basis(&self, rref: &Rref<F, M, N>) -> Vec<Tuple<F, M>> {
+        let columns: Vec<Tuple<F, M>> = self.columns().collect();
+        rref.pivots.iter().map(|&j| columns[j].clone()).collect()
+    }
+
+    fn row_space_basis(&self, rref: &Rref<F, M, N>) -> Vec<Tuple<F, N>> {
+        rref.reduced.rows().take(rref.rank).collect()
+    }
+
+    fn left_null_space_basis(&self, rref: &Rref<F, M, N>) -> Vec<Tuple<F, M>> {
+        rref.transform.rows().skip(rref.rank).collect()
+    }
+}
+
+impl<F: Field, const M: usize, const N: usize> FundamentalSubspaces<F, M, N> {
+    /// Returns the nullity of A, which is the dimension of its null space.
+    pub fn nullity(&self) -> usize {
+        self.null_space.len()
+    }
+
+    /// Returns the dimension of the left null space, which is M - rank(A).
+    pub fn left_nullity(&self) -> usize {
+        self.left_null_space.len()
+    }
+}
+
+impl<F: Field, const M: usize, const N: usize> fmt::Display for FundamentalSubspaces<F, M, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn basis<T: fmt::Display>(vectors: &[T]) -> String {
+            let vectors: Vec<String> = vectors.iter().map(|v| v.to_string()).collect();
+            format!("{{{}}}", vectors.join(", "))
+        }
+        writeln!(f, "rank {}, nullity {}", self.rank, self.nullity())?;
+        writeln!(f, "column space (dim {}): {}", self.rank, basis(&self.column_space))?;
+        writeln!(f, "null space (dim {}): {}", self.nullity(), basis(&self.null_space))?;
+        writeln!(f, "row space (dim {}): {}", self.rank, basis(&self.row_space))?;
+        writeln!(f, "left null space (dim {}): {}", self.left_nullity(), basis(&self.left_null_space))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::field::{IntMod2, Rational};
+    use crate::vector_space::Tuple;
+    use crate::{tuple, matrix};
+
+    #[test]
+    fn fundamental_subspaces() {
+        let a = matrix![[1, 2, 3, 1], [2, 4, 6, 2], [-1, -2, -1, 1]];
+        assert_eq!(a.rank(), 2);
+        assert_eq!(a.nullity(), 2);
+        assert_eq!(a.null_space(), vec![tuple![-2, 1, 0, 0], tuple![2, 0, -1, 1]]);
+        assert_eq!(a.column_space(), vec![tuple![1, 2, -1], tuple![3, 6, -1]]);
+        assert_eq!(a.row_space(), vec![tuple![1, 2, 0, -2], tuple![0, 0, 1, 1]]);
+        let left = a.left_null_space();
+        assert_eq!(left.len(), 1);
+        assert_eq!(&left[0] * a, tuple![0, 0, 0, 0]);
+        for x in a.null_space() {
+            assert_eq!(a * x, tuple![0, 0, 0]);
+        }
+
+        let subspaces = a.fundamental_subspaces();
+        assert_eq!(subspaces.null_space, a.null_space());
+        assert_eq!(subspaces.column_space, a.column_space());
+        assert_eq!(subspaces.row_space, a.row_space());
+        assert_eq!(subspaces.left_null_space, left);
+        assert_eq!(subspaces.rank + subspaces.nullity(), 4);
+        assert_eq!(subspaces.rank + subspaces.left_nullity(), 3);
+        assert_eq!(subspaces.to_string().lines().next(), Some("rank 2, nullity 2"));
+    }
+
+    #[test]
+    fn fundamental_subspaces_over_int_mod_2() {
+        let (o, i) = (IntMod2::Zero, IntMod2::One);
+        let a = matrix![[i, i, o], [o, i, i], [i, o, i]];
+        assert_eq!(a.rank(), 2);
+        assert_eq!(a.null_space(), vec![tuple![i, i, i]]);
+        assert_eq!(a.left_null_space(), vec![tuple![i, i, i]]);
+        let full = matrix![[1, 2], [3, 4]];
+        assert_eq!(full.null_space(), Vec::<Tuple<Rational, 2>>::new());
+        assert!(full.left_null_space().is_empty());
+    }
+}
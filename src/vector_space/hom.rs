@@ -0,0 +1,269 @@
+use crate::field::Field;
+use crate::linear_map::LinearMap;
+use super::{VectorSpace, Entries, FiniteDimensional, Matrix, Tuple};
+use std::ops::{Add, Mul, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssign, Neg};
+use std::marker::PhantomData;
+use std::fmt;
+
+/// A type representing a linear map T: V -> W between finite-dimensional vector spaces, making up
+/// the vector space L(V, W) under pointwise addition and scalar multiplication.
+/// The map is stored as its standard matrix, whose j'th column holds the entries of T(e_j),
+/// listed row by row. These are the entries of the map as a vector, so that
+/// dim L(V, W) = dim(V) dim(W).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Hom<F: Field, V, W>(Vec<F>, PhantomData<(V, W)>);
+
+impl<F: Field, V: FiniteDimensional<F>, W: FiniteDimensional<F>> Hom<F, V, W> {
+    /// Returns the element of L(V, W) which agrees with the given linear map.
+    pub fn from_map(t: &impl LinearMap<F, V, W>) -> Self {
+        Self(t.standard_rows().concat(), PhantomData)
+    }
+
+    /// Returns the unique linear map T with [T]_β^γ = A, which is the inverse of the
+    /// isomorphism Φ: L(V, W) -> M_MxN(F) given by T -> [T]_β^γ (2.20).
+    /// Assumes that β and γ are bases.
+    pub fn from_matrix<const M: usize, const N: usize>(a: &Matrix<F, M, N>, beta: &[V; N], gamma: &[W; M]) -> Self {
+        Self::from_map(&MatrixRelativeTo { a, beta, gamma })
+    }
+}
+
+/// The map φ_γ⁻¹ L_A φ_β, used to build `Hom::from_matrix`.
+struct MatrixRelativeTo<'a, F: Field, V, W, const M: usize, const N: usize> {
+    a: &'a Matrix<F, M, N>,
+    beta: &'a [V; N],
+    gamma: &'a [W; M],
+}
+
+impl<'a, F: Field, V: FiniteDimensional<F>, W: FiniteDimensional<F>, const M: usize, const N: usize> LinearMap<F, V, W> for MatrixRelativeTo<'a, F, V, W, M, N> {
+    fn apply(&self, x: &V) -> W {
+        W::from_coordinates(&self.a.apply(&x.coordinates(self.beta)), self.gamma)
+    }
+}
+
+impl<F: Field, V: FiniteDimensional<F>, W: FiniteDimensional<F>> LinearMap<F, V, W> for Hom<F, V, W> {
+    fn apply(&self, x: &V) -> W {
+        let x = x.entries();
+        let n = V::dim();
+        let entries: Vec<F> = (0..W::dim()).map(|i| {
+            let mut accum = F::zero();
+            for (a, xj) in self.0[i * n..(i + 1) * n].iter().zip(x.iter()) {
+                accum += *a * *xj;
+            }
+            accum
+        }).collect();
+        W::from_entries(&entries)
+    }
+}
+
+// L(F^N, F^M) is identified with M_MxN(F) by taking the standard matrix.
+impl<F: Field, const M: usize, const N: usize> From<Matrix<F, M, N>> for Hom<F, Tuple<F, N>, Tuple<F, M>> {
+    fn from(a: Matrix<F, M, N>) -> Self {
+        Self(a.entries(), PhantomData)
+    }
+}
+impl<F: Field, const M: usize, const N: usize> From<Hom<F, Tuple<F, N>, Tuple<F, M>>> for Matrix<F, M, N> {
+    fn from(t: Hom<F, Tuple<F, N>, Tuple<F, M>>) -> Self {
+        Self::from_entries(&t.0)
+    }
+}
+
+// Operator implementations for maps with maps.
+impl<F: Field, V, W> Neg for Hom<F, V, W> {
+    type Output = Self;
+    fn neg(mut self) -> Self {
+        for a in self.0.iter_mut() {
+            *a = -*a;
+        }
+        self
+    }
+}
+impl<F: Field, V, W> Add<&Self> for Hom<F, V, W> {
+    type Output = Self;
+    fn add(mut self, rhs: &Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl<F: Field, V, W> Add<Self> for Hom<F, V, W> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl<F: Field, V, W> Sub<&Self> for Hom<F, V, W> {
+    type Output = Self;
+    fn sub(mut self, rhs: &Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl<F: Field, V, W> Sub<Self> for Hom<F, V, W> {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl<F: Field, V, W> AddAssign<&Self> for Hom<F, V, W> {
+    fn add_assign(&mut self, rhs: &Self) {
+        for i in 0..self.0.len() {
+            self.0[i] += rhs.0[i];
+        }
+    }
+}
+impl<F: Field, V, W> AddAssign<Self> for Hom<F, V, W> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+impl<F: Field, V, W> SubAssign<&Self> for Hom<F, V, W> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        for i in 0..self.0.len() {
+            self.0[i] -= rhs.0[i];
+        }
+    }
+}
+impl<F: Field, V, W> SubAssign<Self> for Hom<F, V, W> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+// Operator implementations for maps with scalars.
+impl<T: Into<F>, F: Field, V, W> Mul<T> for Hom<F, V, W> {
+    type Output = Self;
+    fn mul(mut self, rhs: T) -> Self {
+        self *= rhs;
+        self
+    }
+}
+impl<T: Into<F>, F: Field, V, W> Div<T> for Hom<F, V, W> {
+    type Output = Self;
+    fn div(mut self, rhs: T) -> Self {
+        self /= rhs;
+        self
+    }
+}
+impl<T: Into<F>, F: Field, V, W> MulAssign<T> for Hom<F, V, W> {
+    fn mul_assign(&mut self, rhs: T) {
+        let scale = rhs.into();
+        for a in self.0.iter_mut() {
+            *a *= scale;
+        }
+    }
+}
+impl<T: Into<F>, F: Field, V, W> DivAssign<T> for Hom<F, V, W> {
+    fn div_assign(&mut self, rhs: T) {
+        let scale = rhs.into();
+        for a in self.0.iter_mut() {
+            *a /= scale;
+        }
+    }
+}
+
+impl<F: Field, V, W> fmt::Display for Hom<F, V, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, a) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", a)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<F: Field, V, W> Entries<F> for Hom<F, V, W> {
+    fn entries(&self) -> Vec<F> {
+        self.0.clone()
+    }
+}
+
+impl<F: Field, V: FiniteDimensional<F>, W: FiniteDimensional<F>> VectorSpace<F> for Hom<F, V, W> {
+    fn zero() -> Self {
+        Self(vec![F::zero(); V::dim() * W::dim()], PhantomData)
+    }
+}
+
+// The standard ordered basis of L(V, W) is made up of the maps whose standard matrices have a
+// single entry 1, so that [T] relative to it is the standard matrix of T read row by row.
+impl<F: Field, V: FiniteDimensional<F>, W: FiniteDimensional<F>> FiniteDimensional<F> for Hom<F, V, W> {
+    fn dim() -> usize {
+        V::dim() * W::dim()
+    }
+
+    fn from_entries(entries: &[F]) -> Self {
+        Self(entries.to_vec(), PhantomData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hom;
+    use crate::field::Rational;
+    use crate::linear_map::{LinearMap, FnMap, Derivative};
+    use crate::inner_product::gram_schmidt;
+    use crate::vector_space::{BoundedPolynom, Entries, FiniteDimensional, Matrix, Subspace, Tuple, is_linearly_independent};
+    use crate::{tuple, matrix, polynom};
+
+    type P2 = BoundedPolynom<Rational, 2>;
+
+    #[test]
+    fn hom_dimension() {
+        assert_eq!(<Hom<Rational, P2, Tuple<Rational, 2>> as FiniteDimensional<Rational>>::dim(), 6);
+        let basis = Hom::<Rational, P2, Tuple<Rational, 2>>::standard_basis();
+        assert!(is_linearly_independent(&basis));
+        assert!(Hom::is_basis(&basis));
+    }
+
+    #[test]
+    fn hom_vector_space() {
+        let d = Hom::from_map(&Derivative::<P2>::new());
+        let evaluate = Hom::from_map(&FnMap::new(|p: &P2| BoundedPolynom::new(polynom![p.polynom().eval_at(Rational::from(1))]).unwrap()));
+        let f = BoundedPolynom::new(polynom![1, 2, 3]).unwrap();
+        let sum = d.clone() * 2 + &evaluate;
+        assert_eq!(sum.apply(&f), BoundedPolynom::new(polynom![10, 12]).unwrap());
+        assert!(is_linearly_independent(&[d.clone(), evaluate]));
+        assert!(!is_linearly_independent(&[d.clone(), d * 3]));
+    }
+
+    #[test]
+    fn hom_matrix_isomorphism() {
+        let a = matrix![[1, 2, 0], [0, 1, -1]];
+        let t = Hom::from(a);
+        assert_eq!(t.apply(&tuple![1, 1, 1]), tuple![3, 0]);
+        assert_eq!(Matrix::from(t.clone()), a);
+
+        // [T]_β^γ recovers the matrix a map was built from.
+        let beta = [tuple![1, 1, 0], tuple![0, 1, 1], tuple![1, 0, 1]];
+        let gamma = [tuple![1, 1], tuple![1, -1]];
+        let u = Hom::from_matrix(&a, &beta, &gamma);
+        assert_eq!(u.matrix_relative_to(&beta, &gamma), a);
+        assert_eq!(u.apply(&beta[0]), tuple![1, 1]);
+        assert_eq!((u.clone() + &t).matrix_relative_to(&beta, &gamma), a + t.matrix_relative_to(&beta, &gamma));
+    }
+
+    #[test]
+    fn hom_subspaces_and_gram_schmidt() {
+        type Operator = Hom<Rational, Tuple<Rational, 2>, Tuple<Rational, 2>>;
+        let identity = Operator::from(matrix![[1, 0], [0, 1]]);
+        let shift = Operator::from(matrix![[0, 1], [0, 0]]);
+        let upper = Operator::from(matrix![[2, 3], [0, 2]]);
+
+        // The upper triangular operators with equal diagonal entries form a plane in L(F^2, F^2).
+        let w = Subspace::<Rational, 4>::span_in(&[identity.clone(), shift.clone(), upper.clone()]);
+        assert_eq!(w.dim(), 2);
+        assert!(w.contains_in(&(identity.clone() * 5 - &shift)));
+        assert!(!w.contains_in(&Operator::from(matrix![[1, 0], [0, 2]])));
+        assert_eq!(Subspace::span_in(&w.basis_in::<Operator>()), w);
+
+        // Orthogonalizing with the Frobenius inner product ⟨S, T⟩ = tr(STᵗ), the sum of the entrywise products.
+        let frobenius = |s: &Operator, t: &Operator| s.entries().iter().zip(t.entries()).fold(Rational::from(0), |accum, (&a, b)| accum + a * b);
+        let orthogonal = gram_schmidt(&frobenius, &[upper, identity, shift]);
+        assert_eq!(orthogonal.len(), 2);
+        assert_eq!(frobenius(&orthogonal[0], &orthogonal[1]), Rational::from(0));
+        assert!(orthogonal.iter().all(|t| w.contains_in(t)));
+    }
+}
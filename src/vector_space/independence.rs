@@ -0,0 +1,97 @@
+use crate::field::Field;
+use super::Entries;
+use super::echelon::row_reduce;
+
+/// Row reduces the matrix whose columns are the entries of the given vectors.
+/// Returns the reduced rows along with the pivot columns, which index the vectors that are not
+/// linear combinations of the vectors before them.
+fn reduce_columns<F: Field, V: Entries<F>>(vectors: &[V]) -> (Vec<Vec<F>>, Vec<usize>) {
+    let columns: Vec<Vec<F>> = vectors.iter().map(|v| v.entries()).collect();
+    let height = columns.iter().map(|c| c.len()).max().unwrap_or(0);
+    let mut rows: Vec<Vec<F>> = (0..height)
+        .map(|i| columns.iter().map(|c| c.get(i).copied().unwrap_or_else(F::zero)).collect())
+        .collect();
+    let pivots = row_reduce(&mut rows);
+    (rows, pivots)
+}
+
+/// Returns whether the given set of vectors is linearly independent.
+pub fn is_linearly_independent<F: Field, V: Entries<F>>(vectors: &[V]) -> bool {
+    reduce_columns(vectors).1.len() == vectors.len()
+}
+
+/// Finds a nontrivial linear combination of the given vectors that equals zero,
+/// returning its coefficients, or `None` if the vectors are linearly independent.
+/// The relation only involves the shortest dependent prefix of the list, so the vectors with
+/// nonzero coefficients form a minimal linearly dependent set.
+pub fn dependence_relation<F: Field, V: Entries<F>>(vectors: &[V]) -> Option<Vec<F>> {
+    let (rows, pivots) = reduce_columns(vectors);
+    let dependent = (0..vectors.len()).find(|&j| !pivots.contains(&j))?;
+    let mut coefficients = vec![F::zero(); vectors.len()];
+    coefficients[dependent] = F::one();
+    for (r, &p) in pivots.iter().enumerate().take_while(|(_, &p)| p < dependent) {
+        coefficients[p] = -rows[r][dependent];
+    }
+    Some(coefficients)
+}
+
+/// Picks a basis for the span of the given vectors out of the vectors themselves.
+/// Each vector is kept exactly when it is not a linear combination of the ones before it.
+pub fn reduce_to_basis<F: Field, V: Entries<F> + Clone>(vectors: &[V]) -> Vec<V> {
+    reduce_columns(vectors).1.iter().map(|&j| vectors[j].clone()).collect()
+}
+
+/// Extends a linearly independent set to a basis for the span of it together with a generating set,
+/// by adjoining vectors from the generating set as in the replacement theorem (1.10).
+/// Assumes that `independent` is linearly independent.
+pub fn extend_to_basis<F: Field, V: Entries<F> + Clone>(independent: &[V], generators: &[V]) -> Vec<V> {
+    let mut vectors = independent.to_vec();
+    vectors.extend_from_slice(generators);
+    reduce_to_basis(&vectors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_linearly_independent, dependence_relation, reduce_to_basis, extend_to_basis};
+    use crate::field::{IntMod2, Rational};
+    use crate::vector_space::Polynom;
+    use crate::{tuple, polynom, matrix};
+
+    #[test]
+    fn independence_tuples() {
+        assert!(is_linearly_independent(&[tuple![1, 0, 0, -1], tuple![0, 1, 0, -1], tuple![0, 0, 1, -1], tuple![0, 0, 0, 1]]));
+        // 1.5 Example 1: 4(1, 3, -4, 2) - 3(2, 2, -4, 0) + 2(1, -3, 2, -4) = 0
+        let vectors = [tuple![1, 3, -4, 2], tuple![2, 2, -4, 0], tuple![1, -3, 2, -4], tuple![-1, 0, 1, 0]];
+        assert!(!is_linearly_independent(&vectors));
+        let coefficients = dependence_relation(&vectors).unwrap();
+        assert_eq!(coefficients, vec![Rational::from(2), Rational::from(-3) / 2, Rational::from(1), Rational::from(0)]);
+        assert_eq!(dependence_relation(&[tuple![1, 0], tuple![0, 1]]), None);
+    }
+
+    #[test]
+    fn independence_polynomials() {
+        let p: [Polynom<Rational>; 3] = [polynom![1, 1], polynom![0, 1, 1], polynom![1, 2, 1]];
+        assert!(!is_linearly_independent(&p));
+        assert_eq!(reduce_to_basis(&p), vec![polynom![1, 1], polynom![0, 1, 1]]);
+        assert!(is_linearly_independent(&[polynom![1, 1], polynom![0, 1, 1], polynom![0, 0, 1]]));
+    }
+
+    #[test]
+    fn independence_matrices() {
+        let m = [matrix![[1, 0], [0, 1]], matrix![[0, 1], [1, 0]], matrix![[1, 1], [1, 1]]];
+        assert_eq!(dependence_relation(&m).unwrap(), vec![Rational::from(-1), Rational::from(-1), Rational::from(1)]);
+        assert_eq!(reduce_to_basis(&m).len(), 2);
+    }
+
+    #[test]
+    fn independence_extend_to_basis() {
+        let standard = [tuple![1, 0, 0], tuple![0, 1, 0], tuple![0, 0, 1]];
+        let basis = extend_to_basis(&[tuple![1, 1, 0], tuple![0, 1, 1]], &standard);
+        assert_eq!(basis, vec![tuple![1, 1, 0], tuple![0, 1, 1], tuple![1, 0, 0]]);
+
+        let (o, i) = (IntMod2::Zero, IntMod2::One);
+        assert!(!is_linearly_independent(&[tuple![i, i, o], tuple![o, i, i], tuple![i, o, i]]));
+        let basis = extend_to_basis(&[tuple![i, i, o], tuple![o, i, i]], &[tuple![i, o, i], tuple![o, o, i]]);
+        assert_eq!(basis, vec![tuple![i, i, o], tuple![o, i, i], tuple![o, o, i]]);
+    }
+}
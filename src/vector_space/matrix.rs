@@ -1,6 +1,8 @@
-use crate::field::{Field, Int, Rational, Real, Complex};
-use super::VectorSpace;
+use crate::field::{Field, ConjugateField, Int, IntMod2, Rational, Real, Complex};
+use super::{VectorSpace, Entries, FiniteDimensional, Tuple, Polynom};
+use super::echelon::row_reduce;
 use std::ops::{Add, Mul, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssign, Index, IndexMut, Neg};
+use std::iter::FromIterator;
 
 /// A type representing a fixed-size matrix over a given field.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -28,6 +30,96 @@ impl<F: Field, const M: usize, const N: usize> Matrix<F, M, N> {
         self[row].clone()
     }
 
+    /// Returns the transpose of the matrix.
+    pub fn transpose(&self) -> Matrix<F, N, M> {
+        let mut out = [[F::zero(); M]; N];
+        for i in 0..M {
+            for j in 0..N {
+                out[j][i] = self[(i, j)];
+            }
+        }
+        Matrix(out)
+    }
+
+    /// Creates the matrix with the given rows.
+    pub fn from_rows(rows: [Tuple<F, N>; M]) -> Self {
+        IntoIterator::into_iter(rows).collect()
+    }
+
+    /// Creates the matrix with the given columns.
+    pub fn from_columns(columns: [Tuple<F, M>; N]) -> Self {
+        Matrix::<F, N, M>::from_rows(columns).transpose()
+    }
+
+    /// Creates the Vandermonde matrix with entries A_ij = x_i^j, for j = 0, ..., N - 1.
+    /// Multiplying it by the coefficients of a polynomial of degree less than N evaluates the polynomial
+    /// at the points x_0, ..., x_(M-1), as in Lagrange interpolation (1.6).
+    pub fn vandermonde(points: &Tuple<F, M>) -> Self {
+        let mut out = Self::zero();
+        for i in 0..M {
+            let mut power = F::one();
+            for j in 0..N {
+                out[(i, j)] = power;
+                power *= points[i];
+            }
+        }
+        out
+    }
+
+    /// Creates the Toeplitz matrix which is constant along each diagonal, with the given first column
+    /// and first row. The diagonal entry is taken from the column, so `first_row[0]` is ignored.
+    pub fn toeplitz(first_column: &Tuple<F, M>, first_row: &Tuple<F, N>) -> Self {
+        let mut out = Self::zero();
+        for i in 0..M {
+            for j in 0..N {
+                out[(i, j)] = if i >= j { first_column[i - j] } else { first_row[j - i] };
+            }
+        }
+        out
+    }
+
+    /// Returns an iterator over the rows of the matrix, as tuples.
+    pub fn rows(&self) -> impl Iterator<Item=Tuple<F, N>> + '_ {
+        (0..M).map(move |i| Tuple::from(self.get_row(i)))
+    }
+
+    /// Returns an iterator over the columns of the matrix, as tuples.
+    pub fn columns(&self) -> impl Iterator<Item=Tuple<F, M>> + '_ {
+        (0..N).map(move |j| Tuple::from(self.get_column(j)))
+    }
+
+    /// Returns an iterator over the entries, row by row.
+    pub fn iter(&self) -> impl Iterator<Item=&F> {
+        self.0.iter().flatten()
+    }
+
+    /// Returns an iterator over mutable references to the entries, row by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item=&mut F> {
+        self.0.iter_mut().flatten()
+    }
+
+    /// Returns the matrix whose entries are f(A_ij).
+    pub fn map<G: Field>(&self, mut f: impl FnMut(F) -> G) -> Matrix<G, M, N> {
+        let mut out = [[G::zero(); N]; M];
+        for i in 0..M {
+            for j in 0..N {
+                out[i][j] = f(self[(i, j)]);
+            }
+        }
+        Matrix(out)
+    }
+
+    /// Returns the matrix whose entries are f(A_ij, B_ij).
+    pub fn zip_with<G: Field, H: Field>(&self, other: &Matrix<G, M, N>, mut f: impl FnMut(F, G) -> H) -> Matrix<H, M, N> {
+        let mut out = [[H::zero(); N]; M];
+        for i in 0..M {
+            for j in 0..N {
+                out[i][j] = f(self[(i, j)], other[(i, j)]);
+            }
+        }
+        Matrix(out)
+    }
+
     /// Multiplies this matrix in-place by the inputted matrix on the right.
     fn right_multiply(&mut self, rhs: &Matrix<F, N, N>) {
         for i in 0..M {
@@ -42,19 +134,174 @@ impl<F: Field, const M: usize, const N: usize> Matrix<F, M, N> {
     }
 
     /// Multiplies this matrix in-place by the inputted matrix on the left.
-    fn left_multiply(&mut self, rhs: &Matrix<F, M, M>) {
-        for i in 0..N {
-            let column: [F;M] = self.get_column(i);
-            for j in 0..M {
+    fn left_multiply(&mut self, lhs: &Matrix<F, M, M>) {
+        for j in 0..N {
+            let column = self.get_column(j);
+            for i in 0..M {
                 self[(i, j)] = F::zero();
                 for k in 0..M {
-                    self[(i, j)] += column[k] * rhs[(k,j)];
+                    self[(i, j)] += lhs[(i, k)] * column[k];
                 }
             }
         }
     }
 }
 
+impl<F: Field, const N: usize> Matrix<F, N, N> {
+    /// Returns the N × N identity matrix I_N.
+    pub fn identity() -> Self {
+        Self::diagonal(&Tuple::from([F::one(); N]))
+    }
+
+    /// Creates the diagonal matrix with the given diagonal entries.
+    pub fn diagonal(entries: &Tuple<F, N>) -> Self {
+        let mut out = Self::zero();
+        for i in 0..N {
+            out[(i, i)] = entries[i];
+        }
+        out
+    }
+
+    /// Creates the permutation matrix sending e_j to e_σ(j), so that its j'th column is e_σ(j).
+    /// Panics if σ is not a permutation of 0, ..., N - 1.
+    pub fn permutation(sigma: &[usize; N]) -> Self {
+        let mut out = Self::zero();
+        for j in 0..N {
+            assert!(sigma[j] < N && !sigma[..j].contains(&sigma[j]), "{:?} is not a permutation", sigma);
+            out[(sigma[j], j)] = F::one();
+        }
+        out
+    }
+
+    /// Creates the Hilbert matrix with entries A_ij = 1 / (i + j + 1), indexing from 0.
+    /// Over a field of characteristic p, this requires 2N - 1 < p.
+    pub fn hilbert() -> Self {
+        let mut out = Self::zero();
+        let mut denominator = F::zero();
+        let mut denominators = Vec::with_capacity(2 * N);
+        for _ in 0..2 * N {
+            denominator += F::one();
+            denominators.push(denominator);
+        }
+        for i in 0..N {
+            for j in 0..N {
+                out[(i, j)] = F::one() / denominators[i + j];
+            }
+        }
+        out
+    }
+
+    /// Creates the companion matrix of a polynomial p(t) = a_0 + a_1 t + ... + t^N of degree N,
+    /// which has ones below the diagonal and last column -a_0, ..., -a_(N-1) (5.4 Ex 19).
+    /// Its characteristic polynomial is (-1)^N p(t). A polynomial which is not monic is first divided
+    /// by its leading coefficient. Panics if the degree of p is not N.
+    pub fn companion(p: &Polynom<F>) -> Self {
+        assert_eq!(p.degree(), Some(N), "the companion matrix of a polynomial of degree {:?} is not {}x{}", p.degree(), N, N);
+        let lead = p[N];
+        let mut out = Self::zero();
+        for i in 0..N {
+            if i > 0 {
+                out[(i, i - 1)] = F::one();
+            }
+            out[(i, N - 1)] = -p[i] / lead;
+        }
+        out
+    }
+
+    /// Creates the circulant matrix with the given first column, each later column being the previous
+    /// one shifted cyclically down by one, so that A_ij = c_(i - j mod N).
+    pub fn circulant(first_column: &Tuple<F, N>) -> Self {
+        let mut out = Self::zero();
+        for i in 0..N {
+            for j in 0..N {
+                out[(i, j)] = first_column[(i + N - j) % N];
+            }
+        }
+        out
+    }
+
+    /// Returns the trace tr(A) = Σ A_ii.
+    pub fn trace(&self) -> F {
+        let mut accum = F::zero();
+        for i in 0..N {
+            accum += self[(i, i)];
+        }
+        accum
+    }
+
+    /// Returns the power A^k, computed by repeated squaring. A^0 is the identity.
+    pub fn pow(&self, mut k: u32) -> Self {
+        let mut out = Self::identity();
+        let mut square = *self;
+        while k > 0 {
+            if k & 1 == 1 {
+                out.left_multiply(&square);
+            }
+            k >>= 1;
+            if k == 0 {
+                break;
+            }
+            square.right_multiply(&square.clone());
+        }
+        out
+    }
+
+    /// Returns the inverse of the matrix, or `None` if the matrix is not invertible.
+    /// The inverse is found by row reducing the augmented matrix (A|I) to (I|A^-1).
+    pub fn inverse(&self) -> Option<Self> {
+        let mut rows: Vec<Vec<F>> = (0..N).map(|i| {
+            let mut row = self[i].to_vec();
+            row.extend((0..N).map(|j| if i == j { F::one() } else { F::zero() }));
+            row
+        }).collect();
+        if row_reduce(&mut rows).iter().take_while(|&&p| p < N).count() < N {
+            return None;
+        }
+        let mut inverse = [[F::zero(); N]; N];
+        for i in 0..N {
+            inverse[i].copy_from_slice(&rows[i][N..]);
+        }
+        Some(Self(inverse))
+    }
+
+    /// Returns the similar matrix Q^-1 A Q, or `None` if Q is not invertible.
+    /// If A = [T]_β and Q changes β' coordinates into β coordinates, this is [T]_β' (2.23).
+    pub fn similarity_transform(&self, q: &Self) -> Option<Self> {
+        Some(q.inverse()? * self * q)
+    }
+}
+
+impl<F: ConjugateField, const M: usize, const N: usize> Matrix<F, M, N> {
+    /// Returns the conjugate transpose A*, with entries (A*)_ij = conj(A_ji). Over the reals this is the transpose.
+    pub fn adjoint(&self) -> Matrix<F, N, M> {
+        self.transpose().map(F::conj)
+    }
+}
+
+// Collecting exactly M rows into a matrix. Panics if the iterator yields any other number of rows.
+impl<F: Field, const M: usize, const N: usize> FromIterator<Tuple<F, N>> for Matrix<F, M, N> {
+    fn from_iter<I: IntoIterator<Item=Tuple<F, N>>>(iter: I) -> Self {
+        let mut arr = [[F::zero(); N]; M];
+        let mut count = 0;
+        for row in iter {
+            assert!(count < M, "too many rows to collect into a matrix with {} rows", M);
+            arr[count] = Into::<[F; N]>::into(row);
+            count += 1;
+        }
+        assert_eq!(count, M, "too few rows to collect into a matrix with {} rows", M);
+        Self(arr)
+    }
+}
+
+// Iterating over entries, row by row
+impl<'a, F: Field, const M: usize, const N: usize> IntoIterator for &'a Matrix<F, M, N> {
+    type Item = &'a F;
+    type IntoIter = std::iter::Flatten<std::slice::Iter<'a, [F; N]>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter().flatten()
+    }
+}
+
 // From double array
 impl<F: Field, const M: usize, const N: usize> From<[[F;N];M]> for Matrix<F, M, N> {
     fn from(arr: [[F;N];M]) -> Self {
@@ -176,10 +423,9 @@ impl<F: Field, const M: usize, const N: usize> SubAssign<Self> for Matrix<F, M,
 }
 
 // Scalar multiplication and division
-impl<T: Into<F>, F: Field, const M: usize, const N: usize> Mul<T> for Matrix<F, M, N> {
+impl<F: Field, const M: usize, const N: usize> Mul<F> for Matrix<F, M, N> {
     type Output = Self;
-    fn mul(mut self, rhs: T) -> Self {
-        let scale = rhs.into();
+    fn mul(mut self, scale: F) -> Self {
         for i in 0..M {
             for j in 0..N {
                 self[(i,j)] *= scale;
@@ -188,10 +434,9 @@ impl<T: Into<F>, F: Field, const M: usize, const N: usize> Mul<T> for Matrix<F,
         self
     }
 }
-impl<T: Into<F>, F: Field, const M: usize, const N: usize> Div<T> for Matrix<F, M, N> {
+impl<F: Field, const M: usize, const N: usize> Div<F> for Matrix<F, M, N> {
     type Output = Self;
-    fn div(mut self, rhs: T) -> Self {
-        let scale = rhs.into();
+    fn div(mut self, scale: F) -> Self {
         for i in 0..M {
             for j in 0..N {
                 self[(i,j)] /= scale;
@@ -200,9 +445,8 @@ impl<T: Into<F>, F: Field, const M: usize, const N: usize> Div<T> for Matrix<F,
         self
     }
 }
-impl<T: Into<F>, F: Field, const M: usize, const N: usize> MulAssign<T> for Matrix<F, M, N> {
-    fn mul_assign(&mut self, rhs: T) {
-        let scale = rhs.into();
+impl<F: Field, const M: usize, const N: usize> MulAssign<F> for Matrix<F, M, N> {
+    fn mul_assign(&mut self, scale: F) {
         for i in 0..M {
             for j in 0..N {
                 self[(i,j)] *= scale;
@@ -210,9 +454,8 @@ impl<T: Into<F>, F: Field, const M: usize, const N: usize> MulAssign<T> for Matr
         }
     }
 }
-impl<T: Into<F>, F: Field, const M: usize, const N: usize> DivAssign<T> for Matrix<F, M, N> {
-    fn div_assign(&mut self, rhs: T) {
-        let scale = rhs.into();
+impl<F: Field, const M: usize, const N: usize> DivAssign<F> for Matrix<F, M, N> {
+    fn div_assign(&mut self, scale: F) {
         for i in 0..M {
             for j in 0..N {
                 self[(i,j)] /= scale;
@@ -221,6 +464,14 @@ impl<T: Into<F>, F: Field, const M: usize, const N: usize> DivAssign<T> for Matr
     }
 }
 
+impl_scalar_conversions! {
+    Matrix[M, N];
+    Rational: Int, IntMod2;
+    Real: Rational, IntMod2;
+    Complex: Real, Rational, IntMod2;
+    IntMod2: Int;
+}
+
 // Matrix multiplication
 impl<F: Field, const M: usize, const N: usize, const P: usize> Mul<&Matrix<F, P, N>> for &Matrix<F, M, P> {
     type Output = Matrix<F, M, N>;
@@ -236,6 +487,107 @@ impl<F: Field, const M: usize, const N: usize, const P: usize> Mul<&Matrix<F, P,
         out
     }
 }
+impl<F: Field, const M: usize, const N: usize, const P: usize> Mul<Matrix<F, P, N>> for &Matrix<F, M, P> {
+    type Output = Matrix<F, M, N>;
+    fn mul(self, rhs: Matrix<F, P, N>) -> Matrix<F, M, N> {
+        Mul::mul(self, &rhs)
+    }
+}
+impl<F: Field, const M: usize, const N: usize, const P: usize> Mul<&Matrix<F, P, N>> for Matrix<F, M, P> {
+    type Output = Matrix<F, M, N>;
+    fn mul(self, rhs: &Matrix<F, P, N>) -> Matrix<F, M, N> {
+        Mul::mul(&self, rhs)
+    }
+}
+impl<F: Field, const M: usize, const N: usize, const P: usize> Mul<Matrix<F, P, N>> for Matrix<F, M, P> {
+    type Output = Matrix<F, M, N>;
+    fn mul(self, rhs: Matrix<F, P, N>) -> Matrix<F, M, N> {
+        Mul::mul(&self, &rhs)
+    }
+}
+
+// In-place multiplication on the right by a square matrix
+impl<F: Field, const M: usize, const N: usize> MulAssign<&Matrix<F, N, N>> for Matrix<F, M, N> {
+    fn mul_assign(&mut self, rhs: &Matrix<F, N, N>) {
+        self.right_multiply(rhs);
+    }
+}
+impl<F: Field, const M: usize, const N: usize> MulAssign<Matrix<F, N, N>> for Matrix<F, M, N> {
+    fn mul_assign(&mut self, rhs: Matrix<F, N, N>) {
+        self.right_multiply(&rhs);
+    }
+}
+
+// Matrix-vector multiplication Ax, with x a column vector
+impl<F: Field, const M: usize, const N: usize> Mul<&Tuple<F, N>> for &Matrix<F, M, N> {
+    type Output = Tuple<F, M>;
+    fn mul(self, rhs: &Tuple<F, N>) -> Tuple<F, M> {
+        let mut out = <Tuple<F, M> as VectorSpace<F>>::zero();
+        for i in 0..M {
+            for j in 0..N {
+                out[i] += self[(i, j)] * rhs[j];
+            }
+        }
+        out
+    }
+}
+impl<F: Field, const M: usize, const N: usize> Mul<Tuple<F, N>> for &Matrix<F, M, N> {
+    type Output = Tuple<F, M>;
+    fn mul(self, rhs: Tuple<F, N>) -> Tuple<F, M> {
+        Mul::mul(self, &rhs)
+    }
+}
+impl<F: Field, const M: usize, const N: usize> Mul<&Tuple<F, N>> for Matrix<F, M, N> {
+    type Output = Tuple<F, M>;
+    fn mul(self, rhs: &Tuple<F, N>) -> Tuple<F, M> {
+        Mul::mul(&self, rhs)
+    }
+}
+impl<F: Field, const M: usize, const N: usize> Mul<Tuple<F, N>> for Matrix<F, M, N> {
+    type Output = Tuple<F, M>;
+    fn mul(self, rhs: Tuple<F, N>) -> Tuple<F, M> {
+        Mul::mul(&self, &rhs)
+    }
+}
+
+// Vector-matrix multiplication xᵗA, with x a row vector
+impl<F: Field, const M: usize, const N: usize> Mul<&Matrix<F, M, N>> for &Tuple<F, M> {
+    type Output = Tuple<F, N>;
+    fn mul(self, rhs: &Matrix<F, M, N>) -> Tuple<F, N> {
+        let mut out = <Tuple<F, N> as VectorSpace<F>>::zero();
+        for j in 0..N {
+            for i in 0..M {
+                out[j] += self[i] * rhs[(i, j)];
+            }
+        }
+        out
+    }
+}
+impl<F: Field, const M: usize, const N: usize> Mul<Matrix<F, M, N>> for &Tuple<F, M> {
+    type Output = Tuple<F, N>;
+    fn mul(self, rhs: Matrix<F, M, N>) -> Tuple<F, N> {
+        Mul::mul(self, &rhs)
+    }
+}
+impl<F: Field, const M: usize, const N: usize> Mul<&Matrix<F, M, N>> for Tuple<F, M> {
+    type Output = Tuple<F, N>;
+    fn mul(self, rhs: &Matrix<F, M, N>) -> Tuple<F, N> {
+        Mul::mul(&self, rhs)
+    }
+}
+impl<F: Field, const M: usize, const N: usize> Mul<Matrix<F, M, N>> for Tuple<F, M> {
+    type Output = Tuple<F, N>;
+    fn mul(self, rhs: Matrix<F, M, N>) -> Tuple<F, N> {
+        Mul::mul(&self, &rhs)
+    }
+}
+
+// Entries are listed row by row.
+impl<F: Field, const M: usize, const N: usize> Entries<F> for Matrix<F, M, N> {
+    fn entries(&self) -> Vec<F> {
+        self.0.iter().flat_map(|row| row.iter().copied()).collect()
+    }
+}
 
 impl<F: Field, const M: usize, const N: usize> VectorSpace<F> for Matrix<F, M, N> {
     fn zero() -> Self {
@@ -243,6 +595,20 @@ impl<F: Field, const M: usize, const N: usize> VectorSpace<F> for Matrix<F, M, N
     }
 }
 
+impl<F: Field, const M: usize, const N: usize> FiniteDimensional<F> for Matrix<F, M, N> {
+    fn dim() -> usize {
+        M * N
+    }
+
+    fn from_entries(entries: &[F]) -> Self {
+        let mut arr = [[F::zero(); N]; M];
+        for i in 0..M {
+            arr[i].copy_from_slice(&entries[i*N..(i+1)*N]);
+        }
+        Self(arr)
+    }
+}
+
 impl<const M: usize, const N: usize> VectorSpace<Real> for Matrix<Complex, M, N> {
     fn zero() -> Self {
         <Matrix<Complex, M, N> as VectorSpace<Complex>>::zero()
@@ -265,6 +631,7 @@ mod tests {
     use super::Matrix;
     use crate::field::Rational;
     use crate::vector_space::VectorSpace;
+    use crate::{tuple, polynom, cmplx};
 
     #[test]
     fn matrix_zero() {
@@ -300,4 +667,112 @@ mod tests {
         assert_eq!(m[(1,0)], 4);
         assert_eq!(m[(1,1)], 5);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn matrix_inverse() {
+        let a = matrix![[1, 2], [3, 4]];
+        let inverse = a.inverse().unwrap();
+        assert_eq!(inverse, matrix![[-4, 2], [3, -1]] / 2);
+        assert_eq!(a * inverse, matrix![[1, 0], [0, 1]]);
+        assert_eq!(matrix![[1, 2], [2, 4]].inverse(), None);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn matrix_products() {
+        let a = matrix![[1, 2], [3, 4]];
+        let b = matrix![[0, 1, 1], [1, 0, 2]];
+        let x = tuple![1, -1];
+        assert_eq!(a * b, matrix![[2, 1, 5], [4, 3, 11]]);
+        assert_eq!(a * &b, &a * &b);
+        assert_eq!(&a * b, &a * &b);
+        assert_eq!(a * x.clone(), tuple![-1, -1]);
+        assert_eq!(&a * &x, tuple![-1, -1]);
+        assert_eq!(x.clone() * a, tuple![-2, -2]);
+        assert_eq!(&x * &b, tuple![-1, 1, -1]);
+        assert_eq!((a * b) * tuple![1, 0, 0], a * (b * tuple![1, 0, 0]));
+
+        let mut c = b;
+        c *= Matrix::identity();
+        assert_eq!(c, b);
+        c *= matrix![[0, 0, 1], [0, 1, 0], [1, 0, 0]];
+        assert_eq!(c, matrix![[1, 1, 0], [2, 0, 1]]);
+        c *= 2;
+        assert_eq!(c, matrix![[2, 2, 0], [4, 0, 2]]);
+    }
+
+    #[test]
+    fn matrix_constructors() {
+        let a = matrix![[1, 2, 3], [4, 5, 6]];
+        assert_eq!(Matrix::from_rows([tuple![1, 2, 3], tuple![4, 5, 6]]), a);
+        assert_eq!(Matrix::from_columns([tuple![1, 4], tuple![2, 5], tuple![3, 6]]), a);
+        assert_eq!(Matrix::diagonal(&tuple![1, 2]), matrix![[1, 0], [0, 2]]);
+        assert_eq!(Matrix::identity(), matrix![[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+        assert_eq!(matrix![[1, 2], [3, 4]].trace(), Rational::from(5));
+
+        let p: Matrix<Rational, 3, 3> = Matrix::permutation(&[1, 2, 0]);
+        assert_eq!(p, matrix![[0, 0, 1], [1, 0, 0], [0, 1, 0]]);
+        assert_eq!(p * tuple![1, 0, 0], tuple![0, 1, 0]);
+        assert_eq!(p.pow(3), Matrix::identity());
+    }
+
+    #[test]
+    fn matrix_structured() {
+        // f(t) = 1 + 2t + t^2 takes the values 1, 4, 9 at t = 0, 1, 2.
+        let v: Matrix<Rational, 3, 3> = Matrix::vandermonde(&tuple![0, 1, 2]);
+        assert_eq!(v, matrix![[1, 0, 0], [1, 1, 1], [1, 2, 4]]);
+        assert_eq!(v * tuple![1, 2, 1], tuple![1, 4, 9]);
+
+        let h: Matrix<Rational, 2, 2> = Matrix::hilbert();
+        assert_eq!(h * 6, matrix![[6, 3], [3, 2]]);
+
+        assert_eq!(Matrix::toeplitz(&tuple![1, 2, 3], &tuple![0, 4]), matrix![[1, 4], [2, 1], [3, 2]]);
+        assert_eq!(Matrix::circulant(&tuple![1, 2, 3]), matrix![[1, 3, 2], [2, 1, 3], [3, 2, 1]]);
+
+        // A companion matrix satisfies its polynomial p(t) = 6 - 5t + t^2, and a non-monic p is normalised.
+        let c = Matrix::companion(&polynom![6, -5, 1]);
+        assert_eq!(c, matrix![[0, -6], [1, 5]]);
+        let identity: Matrix<Rational, 2, 2> = Matrix::identity();
+        assert_eq!(c.pow(2) - c * 5 + identity * 6, Matrix::zero());
+        assert_eq!(Matrix::companion(&polynom![12, -10, 2]), c);
+    }
+
+    #[test]
+    fn matrix_adjoint() {
+        let a = matrix![[cmplx!(1, 1), cmplx!(2)], [cmplx!(0, -3), cmplx!(4, 2)]];
+        assert_eq!(a.adjoint(), matrix![[cmplx!(1, -1), cmplx!(0, 3)], [cmplx!(2), cmplx!(4, -2)]]);
+        assert_eq!(a.adjoint().adjoint(), a);
+        assert_eq!(matrix![[1, 2, 3]].adjoint(), matrix![[1], [2], [3]]);
+    }
+
+    #[test]
+    fn matrix_powers() {
+        let fibonacci = matrix![[1, 1], [1, 0]];
+        assert_eq!(fibonacci.pow(10), matrix![[89, 55], [55, 34]]);
+        assert_eq!(fibonacci.pow(0), Matrix::identity());
+        assert_eq!(fibonacci.pow(1), fibonacci);
+        assert_eq!(matrix![[1, 1], [0, 1]].pow(5), matrix![[1, 5], [0, 1]]);
+        // Squaring once more after the last bit would overflow the entries.
+        let large = matrix![[4294967296]];
+        assert_eq!(large.pow(1), large);
+        let a = matrix![[1, 2, 0], [0, 1, 3], [4, 0, 1]];
+        assert_eq!(a.pow(3), a * a * a);
+    }
+
+    #[test]
+    fn matrix_iteration() {
+        let m = matrix![[1, 2, 3], [4, 5, 6]];
+        assert_eq!(m.rows().collect::<Vec<_>>(), vec![tuple![1, 2, 3], tuple![4, 5, 6]]);
+        assert_eq!(m.columns().collect::<Vec<_>>(), vec![tuple![1, 4], tuple![2, 5], tuple![3, 6]]);
+        assert_eq!(m.iter().fold(Rational::from(0), |acc, &a| acc + a), Rational::from(21));
+        assert_eq!(m.columns().collect::<Matrix<Rational, 3, 2>>(), m.transpose());
+        assert_eq!(m.map(|a| a * a), matrix![[1, 4, 9], [16, 25, 36]]);
+        assert_eq!(m.zip_with(&m, |a, b| a - b), <Matrix<Rational, 2, 3> as VectorSpace<Rational>>::zero());
+
+        let mut n = m;
+        for entry in n.iter_mut() {
+            *entry += Rational::from(1);
+        }
+        assert_eq!((&n).into_iter().next(), Some(&Rational::from(2)));
+    }
+}
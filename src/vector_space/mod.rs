@@ -1,13 +1,81 @@
+/// Implements multiplication and division of a vector type over a field by each listed number type that
+/// converts into the field, by converting the scalar first. These are written out per conversion instead of
+/// over `T: Into<F>`, which would overlap with the products of tuples and matrices with each other.
+macro_rules! impl_scalar_conversions {
+    ($vector:ident $params:tt; $($field:ty: $($scalar:ty),+;)+) => {$($(
+        impl_scalar_conversions!(@impl $vector $params $field, $scalar);
+    )+)+};
+    (@impl $vector:ident [$($param:ident),*] $field:ty, $scalar:ty) => {
+        impl<$(const $param: usize),*> Mul<$scalar> for $vector<$field, $($param),*> {
+            type Output = Self;
+            fn mul(self, rhs: $scalar) -> Self {
+                self * Into::<$field>::into(rhs)
+            }
+        }
+        impl<$(const $param: usize),*> Div<$scalar> for $vector<$field, $($param),*> {
+            type Output = Self;
+            fn div(self, rhs: $scalar) -> Self {
+                self / Into::<$field>::into(rhs)
+            }
+        }
+        impl<$(const $param: usize),*> MulAssign<$scalar> for $vector<$field, $($param),*> {
+            fn mul_assign(&mut self, rhs: $scalar) {
+                *self *= Into::<$field>::into(rhs);
+            }
+        }
+        impl<$(const $param: usize),*> DivAssign<$scalar> for $vector<$field, $($param),*> {
+            fn div_assign(&mut self, rhs: $scalar) {
+                *self /= Into::<$field>::into(rhs);
+            }
+        }
+    };
+}
+
 mod tuple;
 mod matrix;
 mod polynom;
 mod funct;
 mod zero;
+pub(crate) mod echelon;
+mod subspace;
+mod independence;
+mod finite_dimensional;
+mod bounded_polynom;
+mod dual;
+mod tensor_product;
+mod exterior_power;
+mod hom;
+mod dvector;
+mod dmatrix;
+mod sparse;
+mod complexify;
+mod finite_field;
+mod elementary;
+mod fundamental;
+mod determinant;
 
 pub use matrix::Matrix;
 pub use tuple::Tuple;
 pub use polynom::Polynom;
 pub use funct::Funct; 
+pub use zero::Zero;
+pub use bounded_polynom::BoundedPolynom;
+pub use dual::Dual;
+pub use tensor_product::TensorProduct;
+pub use exterior_power::{ExteriorPower, determinant};
+pub use hom::Hom;
+pub use dvector::{DVector, DimensionMismatch};
+pub use dmatrix::DMatrix;
+pub use sparse::SparseMatrix;
+pub use complexify::{Complexify, Realification};
+pub use finite_field::gaussian_binomial;
+pub use elementary::{ElementaryOperation, OperationLog, Side};
+pub use echelon::Rref;
+pub use fundamental::FundamentalSubspaces;
+pub(crate) use exterior_power::subsets;
+pub use subspace::Subspace;
+pub use independence::{is_linearly_independent, dependence_relation, reduce_to_basis, extend_to_basis};
+pub use finite_dimensional::FiniteDimensional;
 use crate::field::Field;
 use std::ops::{Add, Mul, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssign, Neg};
 
@@ -16,7 +84,7 @@ use std::ops::{Add, Mul, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssign, N
 /// Scalar multiplication must be associative and have identity Scalar::one().
 /// Addition and multiplication must obey distributive laws.
 /// All vectors must have an additive inverse.
-trait VectorSpace<Scalar: Field>: Sized 
+pub trait VectorSpace<Scalar: Field>: Sized 
     + 'static
     + Neg<Output=Self>
     + Add<&'static Self, Output=Self>
@@ -33,4 +101,12 @@ trait VectorSpace<Scalar: Field>: Sized
     + DivAssign<Scalar> {
     // Returns the zero element of the vector space, which must be the identity for vector addition.
     fn zero() -> Self;
+}
+
+/// A trait for vector types whose elements can be written as a finite list of scalars,
+/// relative to a fixed standard basis. Lists of different lengths are compared by padding the
+/// shorter ones with zeros, so that row reduction can be run on general vector types.
+pub trait Entries<F: Field> {
+    /// Returns the entries of the vector relative to the standard basis.
+    fn entries(&self) -> Vec<F>;
 }
\ No newline at end of file
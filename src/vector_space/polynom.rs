@@ -1,6 +1,7 @@
 use crate::field::{Field, Real, Complex, Int, Rational};
-use super::VectorSpace;
+use super::{VectorSpace, Entries};
 use std::ops::{Add, Mul, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssign, Index, IndexMut, Neg};
+use std::iter::FromIterator;
 use std::{fmt, mem};
 
 #[derive(PartialEq, Debug, Clone)]
@@ -40,8 +41,24 @@ impl<F: Field> Polynom<F> {
         }
     }
 
+    /// Returns the degree of the polynomial, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.len().checked_sub(1)
+    }
+
+    /// Returns the derivative of the polynomial.
+    pub fn derivative(&self) -> Self {
+        let mut coefficients = Vec::new();
+        let mut power = F::zero();
+        for i in 1..self.len() {
+            power += F::one();
+            coefficients.push(self[i] * power);
+        }
+        Self::from(coefficients)
+    }
+
     /// Evaluates the polynomial at the given x value.
-    fn eval_at(&self, x: F) -> F {
+    pub fn eval_at(&self, x: F) -> F {
         let mut exponential = F::one();
         let mut accum = F::zero();
         for i in 0..self.len() {
@@ -50,6 +67,44 @@ impl<F: Field> Polynom<F> {
         }
         accum
     }
+
+    /// Returns an iterator over the coefficients, starting from the constant term.
+    pub fn coefficients(&self) -> std::slice::Iter<'_, F> {
+        self.0.iter()
+    }
+
+    /// Returns an iterator over the nonzero terms, as pairs (degree, coefficient) in increasing degree.
+    pub fn terms(&self) -> impl Iterator<Item=(usize, F)> + '_ {
+        self.coefficients().copied().enumerate().filter(|(_, c)| *c != F::zero())
+    }
+
+    /// Returns the polynomial whose coefficients are f(a_i). Coefficients sent to zero are dropped.
+    pub fn map<G: Field>(&self, f: impl FnMut(F) -> G) -> Polynom<G> {
+        self.coefficients().copied().map(f).collect()
+    }
+}
+
+// Collecting coefficients, starting from the constant term.
+impl<F: Field> FromIterator<F> for Polynom<F> {
+    fn from_iter<I: IntoIterator<Item=F>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<F>>())
+    }
+}
+
+// Iterating over coefficients, starting from the constant term
+impl<F: Field> IntoIterator for Polynom<F> {
+    type Item = F;
+    type IntoIter = std::vec::IntoIter<F>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+impl<'a, F: Field> IntoIterator for &'a Polynom<F> {
+    type Item = &'a F;
+    type IntoIter = std::slice::Iter<'a, F>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
 }
 
 // From vec and array
@@ -215,6 +270,13 @@ impl<F: Field> fmt::Display for Polynom<F> {
     }
 }
 
+// Entries are the coefficients, relative to the basis {1, x, x^2, ...}.
+impl<F: Field> Entries<F> for Polynom<F> {
+    fn entries(&self) -> Vec<F> {
+        self.0.clone()
+    }
+}
+
 impl<F: Field> VectorSpace<F> for Polynom<F> {
     fn zero() -> Self {
         Self(vec![])
@@ -250,4 +312,18 @@ mod test {
         assert_eq!(polynom![3, 4, 0, -7, 2] + polynom![7, -6, 2, 8], polynom![10, -2, 2, 1, 2]);
         assert_eq!(polynom![-6, 8, 7, -3] + polynom![10, -8, 0, 2], polynom![4, 0, 7, -1]);
     }
+
+    #[test]
+    fn polynomial_iteration() {
+        use crate::field::Rational;
+        use crate::vector_space::Polynom;
+        let p = polynom![1, 0, -2, 3];
+        assert_eq!(p.coefficients().count(), 4);
+        let terms: Vec<(usize, Rational)> = p.terms().collect();
+        assert_eq!(terms, vec![(0, Rational::from(1)), (2, Rational::from(-2)), (3, Rational::from(3))]);
+        assert_eq!(p.map(|a| a * a), polynom![1, 0, 4, 9]);
+        assert_eq!(p.map(|a| a * Rational::from(0)), polynom![]);
+        let collected: Polynom<Rational> = p.clone().into_iter().rev().collect();
+        assert_eq!(collected, polynom![3, -2, 0, 1]);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,394 @@
+use crate::field::{Field, Real};
+use super::{DVector, DMatrix, DimensionMismatch};
+use std::collections::BTreeMap;
+
+/// Rows of a sparse matrix, each as a map from columns to nonzero entries.
+type SparseRows<F> = Vec<BTreeMap<usize, F>>;
+
+/// Over inexact fields, a pivot candidate must be at least this fraction of the largest remaining
+/// entry in its column (threshold pivoting), trading some sparsity for stability.
+const THRESHOLD: Real = 0.1;
+
+/// Entries of an inexact field smaller than this, relative to the largest entry originally in their
+/// column (or in the right hand side), are treated as zero.
+const TOLERANCE: Real = 1e-10;
+
+/// A type representing a sparse matrix over a given field, in compressed sparse row (CSR) form.
+/// Only the nonzero entries are stored: the entries of row i are `values[row_offsets[i]..row_offsets[i + 1]]`,
+/// in increasing order of their columns, which are held in `column_indices`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseMatrix<F: Field> {
+    rows: usize,
+    columns: usize,
+    row_offsets: Vec<usize>,
+    column_indices: Vec<usize>,
+    values: Vec<F>,
+}
+
+impl<F: Field> SparseMatrix<F> {
+    /// Creates the zero matrix with the given number of rows and columns.
+    pub fn zeros(rows: usize, columns: usize) -> Self {
+        Self::from_triplets(rows, columns, &[])
+    }
+
+    /// Creates a matrix from its coordinate (COO) form, a list of triplets (i, j, A_ij).
+    /// Entries given more than once are summed, and zero entries are dropped.
+    /// Panics if any index is out of bounds.
+    pub fn from_triplets(rows: usize, columns: usize, triplets: &[(usize, usize, F)]) -> Self {
+        let mut entries: SparseRows<F> = vec![BTreeMap::new(); rows];
+        for &(i, j, a) in triplets.iter() {
+            assert!(i < rows && j < columns, "index ({}, {}) out of bounds for a {}x{} matrix", i, j, rows, columns);
+            *entries[i].entry(j).or_insert_with(F::zero) += a;
+        }
+        Self::from_sparse_rows(columns, entries)
+    }
+
+    /// Creates a matrix from its rows, each given as a map from columns to entries.
+    fn from_sparse_rows(columns: usize, entries: SparseRows<F>) -> Self {
+        let mut out = Self {
+            rows: entries.len(),
+            columns,
+            row_offsets: vec![0],
+            column_indices: Vec::new(),
+            values: Vec::new(),
+        };
+        for row in entries.into_iter() {
+            for (j, a) in row.into_iter().filter(|(_, a)| *a != F::zero()) {
+                out.column_indices.push(j);
+                out.values.push(a);
+            }
+            out.row_offsets.push(out.values.len());
+        }
+        out
+    }
+
+    /// Returns the rows of the matrix, each as a map from columns to nonzero entries.
+    fn to_sparse_rows(&self) -> SparseRows<F> {
+        (0..self.rows).map(|i| self.row(i).collect()).collect()
+    }
+
+    /// Returns the nonzero entries of row i, as pairs (j, A_ij).
+    fn row(&self, i: usize) -> impl Iterator<Item=(usize, F)> + '_ {
+        let range = self.row_offsets[i]..self.row_offsets[i + 1];
+        self.column_indices[range.clone()].iter().copied().zip(self.values[range].iter().copied())
+    }
+
+    /// Returns the shape (rows, columns) of the matrix.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.columns)
+    }
+
+    /// Returns the number of stored nonzero entries.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the entry in row i and column j, or `None` if either is out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> Option<F> {
+        if i >= self.rows || j >= self.columns {
+            return None;
+        }
+        Some(self.row(i).find(|&(k, _)| k == j).map_or(F::zero(), |(_, a)| a))
+    }
+
+    /// Returns the coordinate (COO) form of the matrix, listing the nonzero entries row by row.
+    pub fn triplets(&self) -> Vec<(usize, usize, F)> {
+        (0..self.rows).flat_map(|i| self.row(i).map(move |(j, a)| (i, j, a))).collect()
+    }
+
+    /// Returns the transpose of the matrix.
+    pub fn transpose(&self) -> Self {
+        let swapped: Vec<(usize, usize, F)> = self.triplets().into_iter().map(|(i, j, a)| (j, i, a)).collect();
+        Self::from_triplets(self.columns, self.rows, &swapped)
+    }
+
+    /// Returns the product Ax, or an error if x doesn't have as many entries as A has columns.
+    pub fn try_apply(&self, x: &DVector<F>) -> Result<DVector<F>, DimensionMismatch> {
+        DimensionMismatch::check((self.columns, 1), (x.len(), 1))?;
+        let mut out = DVector::zeros(self.rows);
+        for i in 0..self.rows {
+            for (j, a) in self.row(i) {
+                out[i] += a * x[j];
+            }
+        }
+        Ok(out)
+    }
+
+    /// Returns the dense product AB, or an error if B doesn't have as many rows as A has columns.
+    pub fn try_mul_dense(&self, other: &DMatrix<F>) -> Result<DMatrix<F>, DimensionMismatch> {
+        let (rows, columns) = other.shape();
+        DimensionMismatch::check((self.columns, columns), (rows, columns))?;
+        let mut out = DMatrix::zeros(self.rows, columns);
+        for i in 0..self.rows {
+            for (k, a) in self.row(i) {
+                for j in 0..columns {
+                    out[(i, j)] += a * other[(k, j)];
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Returns the sparse product AB, or an error if B doesn't have as many rows as A has columns.
+    /// Row i of AB is accumulated as the combination of the rows of B picked out by row i of A.
+    pub fn try_mul(&self, other: &Self) -> Result<Self, DimensionMismatch> {
+        DimensionMismatch::check((self.columns, other.columns), other.shape())?;
+        let entries = (0..self.rows).map(|i| {
+            let mut row = BTreeMap::new();
+            for (k, a) in self.row(i) {
+                for (j, b) in other.row(k) {
+                    *row.entry(j).or_insert_with(F::zero) += a * b;
+                }
+            }
+            row
+        }).collect();
+        Ok(Self::from_sparse_rows(other.columns, entries))
+    }
+
+    /// Returns the matrix in dense form.
+    pub fn to_dense(&self) -> DMatrix<F> {
+        let mut out = DMatrix::zeros(self.rows, self.columns);
+        for (i, j, a) in self.triplets() {
+            out[(i, j)] = a;
+        }
+        out
+    }
+
+    /// Runs Gaussian elimination on the rows of A, carrying along the right hand side b.
+    /// Each pivot is chosen by the Markowitz criterion, which picks the nonzero A_ij among the remaining
+    /// rows and columns minimizing (r_i - 1)(c_j - 1), where r_i and c_j count the nonzero entries in its
+    /// row and column. This bounds the fill-in created when eliminating with that pivot.
+    /// Over an exact field any nonzero entry is a valid pivot, so no numerical pivoting is needed.
+    /// Over `Real` and `Complex`, entries within a tolerance of zero are dropped, and only entries at
+    /// least `THRESHOLD` times the largest remaining entry in their column are pivot candidates.
+    /// Returns the reduced rows, right hand side, and the (row, column) of each pivot in order.
+    fn eliminate(&self, mut rhs: Vec<F>) -> (SparseRows<F>, Vec<F>, Vec<(usize, usize)>) {
+        let mut rows = self.to_sparse_rows();
+        let mut tolerances: Vec<Real> = vec![0.0; self.columns];
+        for (_, j, a) in self.triplets() {
+            if let Some(size) = a.magnitude() {
+                tolerances[j] = tolerances[j].max(size * TOLERANCE);
+            }
+        }
+        let is_zero = |a: F, j: usize| match a.magnitude() {
+            Some(size) => size <= tolerances[j],
+            None => a == F::zero(),
+        };
+        for row in rows.iter_mut() {
+            row.retain(|&j, a| !is_zero(*a, j));
+        }
+        let mut active_rows: Vec<bool> = vec![true; self.rows];
+        let mut active_columns: Vec<bool> = vec![true; self.columns];
+        let mut pivots = Vec::new();
+        loop {
+            let mut column_counts = vec![0; self.columns];
+            let mut column_max: Vec<Real> = vec![0.0; self.columns];
+            for (row, _) in rows.iter().zip(active_rows.iter()).filter(|(_, &active)| active) {
+                for (&j, &a) in row.iter().filter(|(&j, _)| active_columns[j]) {
+                    column_counts[j] += 1;
+                    column_max[j] = column_max[j].max(a.magnitude().unwrap_or(0.0));
+                }
+            }
+            let mut best: Option<(usize, usize, usize)> = None;
+            for i in (0..self.rows).filter(|&i| active_rows[i]) {
+                let row_count = rows[i].keys().filter(|&&j| active_columns[j]).count();
+                for (&j, &a) in rows[i].iter().filter(|(&j, _)| active_columns[j]) {
+                    if matches!(a.magnitude(), Some(size) if size < THRESHOLD * column_max[j]) {
+                        continue;
+                    }
+                    let cost = (row_count - 1) * (column_counts[j] - 1);
+                    if !matches!(best, Some((_, _, c)) if c <= cost) {
+                        best = Some((i, j, cost));
+                    }
+                }
+            }
+            let (p, q) = match best {
+                Some((p, q, _)) => (p, q),
+                None => break,
+            };
+            let pivot_row = rows[p].clone();
+            let pivot = pivot_row[&q];
+            for i in (0..self.rows).filter(|&i| active_rows[i] && i != p) {
+                let factor = match rows[i].get(&q) {
+                    Some(&a) => a / pivot,
+                    None => continue,
+                };
+                for (&j, &a) in pivot_row.iter() {
+                    let entry = rows[i].entry(j).or_insert_with(F::zero);
+                    *entry -= factor * a;
+                    if is_zero(*entry, j) {
+                        rows[i].remove(&j);
+                    }
+                }
+                rows[i].remove(&q);
+                let b = rhs[p];
+                rhs[i] -= factor * b;
+            }
+            active_rows[p] = false;
+            active_columns[q] = false;
+            pivots.push((p, q));
+        }
+        (rows, rhs, pivots)
+    }
+
+    /// Returns the rank of the matrix, found by sparse elimination.
+    pub fn rank(&self) -> usize {
+        self.eliminate(vec![F::zero(); self.rows]).2.len()
+    }
+
+    /// Returns a solution of the system Ax = b, or `None` if the system is inconsistent.
+    /// Free variables are set to zero. Returns an error if b doesn't have as many entries as A has rows.
+    pub fn solve(&self, b: &DVector<F>) -> Result<Option<DVector<F>>, DimensionMismatch> {
+        DimensionMismatch::check((self.rows, 1), (b.len(), 1))?;
+        let (rows, rhs, pivots) = self.eliminate((0..self.rows).map(|i| b[i]).collect());
+        // Rows which were never pivots have been reduced to zero, so the system is consistent
+        // exactly when their right hand sides are zero too.
+        let mut is_pivot_row = vec![false; self.rows];
+        for &(p, _) in pivots.iter() {
+            is_pivot_row[p] = true;
+        }
+        let tolerance = (0..self.rows).filter_map(|i| b[i].magnitude()).fold(0.0, Real::max) * TOLERANCE;
+        let is_zero = |a: F| match a.magnitude() {
+            Some(size) => size <= tolerance,
+            None => a == F::zero(),
+        };
+        if (0..self.rows).any(|i| !is_pivot_row[i] && !is_zero(rhs[i])) {
+            return Ok(None);
+        }
+        // Each pivot row only involves its own pivot column, the columns of later pivots, and free
+        // columns, so back substitution in reverse pivot order finds x.
+        let mut x = DVector::zeros(self.columns);
+        for &(p, q) in pivots.iter().rev() {
+            let mut value = rhs[p];
+            for (&j, &a) in rows[p].iter().filter(|(&j, _)| j != q) {
+                value -= a * x[j];
+            }
+            x[q] = value / rows[p][&q];
+        }
+        Ok(Some(x))
+    }
+}
+
+impl<F: Field> From<&DMatrix<F>> for SparseMatrix<F> {
+    fn from(a: &DMatrix<F>) -> Self {
+        let (rows, columns) = a.shape();
+        let mut triplets = Vec::new();
+        for i in 0..rows {
+            for j in 0..columns {
+                if a[(i, j)] != F::zero() {
+                    triplets.push((i, j, a[(i, j)]));
+                }
+            }
+        }
+        Self::from_triplets(rows, columns, &triplets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SparseMatrix;
+    use crate::field::{IntMod2, Rational};
+    use crate::vector_space::{DMatrix, DVector};
+    use crate::{tuple, matrix};
+
+    #[test]
+    fn sparse_construction() {
+        let r = Rational::from;
+        let a = SparseMatrix::from_triplets(2, 3, &[(0, 2, r(1)), (1, 0, r(2)), (0, 2, r(3)), (1, 1, r(0))]);
+        assert_eq!(a.nnz(), 2);
+        assert_eq!(a.get(0, 2), Some(r(4)));
+        assert_eq!(a.get(0, 0), Some(r(0)));
+        assert_eq!(a.get(2, 0), None);
+        assert_eq!(a.triplets(), vec![(0, 2, r(4)), (1, 0, r(2))]);
+        assert_eq!(a.to_dense(), DMatrix::from(matrix![[0, 0, 4], [2, 0, 0]]));
+        assert_eq!(SparseMatrix::from(&a.to_dense()), a);
+        assert_eq!(a.transpose().to_dense(), a.to_dense().transpose());
+    }
+
+    #[test]
+    fn sparse_products() {
+        let a = DMatrix::from(matrix![[1, 0, 2], [0, 0, 3]]);
+        let b = DMatrix::from(matrix![[0, 1], [4, 0], [1, 1]]);
+        let (sa, sb) = (SparseMatrix::from(&a), SparseMatrix::from(&b));
+        assert_eq!(sa.try_mul(&sb).map(|c| c.to_dense()), a.try_mul(&b));
+        assert_eq!(sa.try_mul_dense(&b), a.try_mul(&b));
+        assert!(sa.try_mul(&sa).is_err());
+        let x = DVector::from(tuple![1, 1, 1]);
+        assert_eq!(sa.try_apply(&x), a.try_apply(&x));
+    }
+
+    #[test]
+    fn sparse_incidence_matrix() {
+        // The incidence matrix of the path 0 - 1 - 2 - 3, with rows for edges, has rank 3.
+        let r = Rational::from;
+        let edges = [(0, 1), (1, 2), (2, 3)];
+        let mut triplets = Vec::new();
+        for (e, &(u, v)) in edges.iter().enumerate() {
+            triplets.push((e, u, r(-1)));
+            triplets.push((e, v, r(1)));
+        }
+        let a = SparseMatrix::from_triplets(3, 4, &triplets);
+        assert_eq!(a.rank(), 3);
+        let b = DVector::from(tuple![1, 2, 3]);
+        let x = a.solve(&b).unwrap().unwrap();
+        assert_eq!(a.try_apply(&x), Ok(b));
+    }
+
+    #[test]
+    fn sparse_parity_check() {
+        // The parity-check matrix of the Hamming (7, 4) code, whose j'th column is j + 1 in binary.
+        let mut triplets = Vec::new();
+        for j in 0..7 {
+            for i in 0..3 {
+                if (j + 1) >> i & 1 == 1 {
+                    triplets.push((i, j, IntMod2::One));
+                }
+            }
+        }
+        let h = SparseMatrix::from_triplets(3, 7, &triplets);
+        assert_eq!(h.rank(), 3);
+        let syndrome = DVector::from(tuple![IntMod2::One, IntMod2::Zero, IntMod2::One]);
+        let x = h.solve(&syndrome).unwrap().unwrap();
+        assert_eq!(h.try_apply(&x), Ok(syndrome));
+
+        let inconsistent = SparseMatrix::from_triplets(2, 1, &[(0, 0, IntMod2::One), (1, 0, IntMod2::One)]);
+        assert_eq!(inconsistent.solve(&DVector::from(tuple![IntMod2::One, IntMod2::Zero])), Ok(None));
+    }
+
+    #[test]
+    fn sparse_threshold_pivoting() {
+        // Eliminating with the 1e-17 entry as a pivot would swamp the other entries with rounding error.
+        let a = SparseMatrix::from_triplets(2, 2, &[(0, 0, 1e-17), (0, 1, 1.0), (1, 0, 1.0), (1, 1, 1.0)]);
+        assert_eq!(a.rank(), 2);
+        let x = a.solve(&DVector::new(vec![1.0, 2.0])).unwrap().unwrap();
+        assert!((x[0] - 1.0).abs() < 1e-12 && (x[1] - 1.0).abs() < 1e-12);
+
+        // The second row is the first up to rounding, so the rank is 1 and the system is consistent.
+        let b = SparseMatrix::from_triplets(2, 2, &[(0, 0, 0.1), (0, 1, 0.2), (1, 0, 0.3), (1, 1, 0.1 * 3.0 * 2.0)]);
+        assert_eq!(b.rank(), 1);
+        assert!(b.solve(&DVector::new(vec![0.1, 0.3])).unwrap().is_some());
+        assert_eq!(b.solve(&DVector::new(vec![0.1, 1.0])), Ok(None));
+    }
+
+    #[test]
+    fn sparse_markowitz_fill_in() {
+        // An arrowhead matrix has a dense first row and column. Pivoting on the corner first would fill
+        // in the whole matrix, while the Markowitz criterion avoids it and creates no fill-in.
+        let n = 6;
+        let r = Rational::from;
+        let mut triplets = vec![(0, 0, r(n as i64))];
+        for k in 1..n {
+            triplets.push((0, k, r(1)));
+            triplets.push((k, 0, r(1)));
+            triplets.push((k, k, r(1)));
+        }
+        let a = SparseMatrix::from_triplets(n, n, &triplets);
+        let (rows, _, pivots) = a.eliminate(vec![r(0); n]);
+        assert_eq!(pivots.len(), n);
+        assert_ne!(pivots[0], (0, 0));
+        assert!(rows.iter().map(|row| row.len()).sum::<usize>() <= a.nnz());
+        let b = DVector::new((0..n).map(|i| r(i as i64)).collect());
+        let x = a.solve(&b).unwrap().unwrap();
+        assert_eq!(a.try_apply(&x), Ok(b));
+    }
+}
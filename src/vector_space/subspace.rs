@@ -0,0 +1,209 @@
+use crate::field::Field;
+use super::{Tuple, VectorSpace, FiniteDimensional};
+use super::echelon::row_reduce;
+
+/// A type representing a subspace of F^N.
+/// The subspace is stored as the nonzero rows of the reduced row echelon form of any spanning set.
+/// Since this form is unique, two subspaces are equal exactly when their stored bases are equal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Subspace<F: Field, const N: usize> {
+    basis: Vec<Tuple<F, N>>,
+}
+
+impl<F: Field, const N: usize> Subspace<F, N> {
+    /// Creates the subspace spanned by the given vectors.
+    pub fn span(vectors: &[Tuple<F, N>]) -> Self {
+        Self::from_rows(vectors.iter().map(to_row).collect())
+    }
+
+    /// Creates the zero subspace {0}.
+    pub fn trivial() -> Self {
+        Self {
+            basis: vec![]
+        }
+    }
+
+    /// Creates the subspace consisting of all of F^N.
+    pub fn whole() -> Self {
+        Self::span(&standard_basis())
+    }
+
+    /// Row reduces the given rows and keeps the nonzero ones as the basis.
+    fn from_rows(mut rows: Vec<Vec<F>>) -> Self {
+        let rank = row_reduce(&mut rows).len();
+        Self {
+            basis: rows.iter().take(rank).map(|row| to_tuple(row)).collect()
+        }
+    }
+
+    /// Returns the dimension of the subspace.
+    pub fn dim(&self) -> usize {
+        self.basis.len()
+    }
+
+    /// Returns a basis for the subspace, in reduced row echelon form.
+    pub fn basis(&self) -> &[Tuple<F, N>] {
+        &self.basis
+    }
+
+    /// Returns the columns in which the basis vectors have their leading ones.
+    fn pivots(&self) -> Vec<usize> {
+        self.basis.iter()
+            .map(|v| (0..N).find(|&i| v[i] != F::zero()).unwrap())
+            .collect()
+    }
+
+    /// Returns whether the given vector lies in the subspace.
+    pub fn contains(&self, v: &Tuple<F, N>) -> bool {
+        let mut rows: Vec<Vec<F>> = self.basis.iter().map(to_row).collect();
+        rows.push(to_row(v));
+        row_reduce(&mut rows).len() == self.dim()
+    }
+
+    /// Returns whether every vector of this subspace lies in `other`.
+    pub fn is_subspace_of(&self, other: &Self) -> bool {
+        self.basis.iter().all(|v| other.contains(v))
+    }
+
+    /// Returns the sum W1 + W2 = {x + y : x in W1, y in W2}.
+    pub fn sum(&self, other: &Self) -> Self {
+        Self::from_rows(self.basis.iter().chain(other.basis.iter()).map(to_row).collect())
+    }
+
+    /// Returns the intersection of two subspaces, computed with the Zassenhaus algorithm.
+    /// The rows (u, u) for u in W1 and (w, 0) for w in W2 are row reduced.
+    /// The rows whose first half vanishes then have a basis of W1 ∩ W2 as their second half.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut rows = Vec::new();
+        for u in self.basis.iter() {
+            let mut row = to_row(u);
+            row.extend(to_row(u));
+            rows.push(row);
+        }
+        for w in other.basis.iter() {
+            let mut row = to_row(w);
+            row.extend(vec![F::zero(); N]);
+            rows.push(row);
+        }
+        let pivots = row_reduce(&mut rows);
+        let intersection = rows.iter()
+            .zip(pivots.iter())
+            .filter(|(_, &p)| p >= N)
+            .map(|(row, _)| row[N..].to_vec())
+            .collect();
+        Self::from_rows(intersection)
+    }
+
+    /// Returns a complement W' of this subspace W, so that F^N = W ⊕ W'.
+    /// The complement is spanned by the standard vectors for the non-pivot columns of the basis.
+    pub fn complement(&self) -> Self {
+        let pivots = self.pivots();
+        let standard = standard_basis::<F, N>();
+        let free: Vec<Tuple<F, N>> = (0..N)
+            .filter(|i| !pivots.contains(i))
+            .map(|i| standard[i].clone())
+            .collect();
+        Self::span(&free)
+    }
+
+    /// Returns whether this subspace is the direct sum of W1 and W2,
+    /// which is the case when W1 + W2 is this subspace and W1 ∩ W2 = {0}.
+    pub fn is_direct_sum_of(&self, w1: &Self, w2: &Self) -> bool {
+        w1.intersection(w2).dim() == 0 && &w1.sum(w2) == self
+    }
+}
+
+// Subspaces of any N-dimensional space V, such as the space of linear maps `Hom`, are stored through
+// the isomorphism φ: V -> F^N taking each vector to its coordinates relative to the standard ordered basis (2.21).
+impl<F: Field, const N: usize> Subspace<F, N> {
+    /// Creates the subspace of V spanned by the given vectors, by taking the span of their coordinates.
+    /// Panics if V does not have dimension N.
+    pub fn span_in<V: FiniteDimensional<F>>(vectors: &[V]) -> Self {
+        assert_eq!(V::dim(), N, "a subspace of F^{} cannot hold vectors of a space of dimension {}", N, V::dim());
+        Self::from_rows(vectors.iter().map(|v| v.entries()).collect())
+    }
+
+    /// Returns a basis for the subspace as vectors of V. Panics if V does not have dimension N.
+    pub fn basis_in<V: FiniteDimensional<F>>(&self) -> Vec<V> {
+        assert_eq!(V::dim(), N, "a subspace of F^{} cannot hold vectors of a space of dimension {}", N, V::dim());
+        self.basis.iter().map(|v| V::from_entries(&to_row(v))).collect()
+    }
+
+    /// Returns whether the given vector of V lies in the subspace. Panics if V does not have dimension N.
+    pub fn contains_in<V: FiniteDimensional<F>>(&self, v: &V) -> bool {
+        assert_eq!(V::dim(), N, "a subspace of F^{} cannot hold vectors of a space of dimension {}", N, V::dim());
+        self.contains(&to_tuple(&v.entries()))
+    }
+}
+
+/// Returns the standard basis e_1, ..., e_N of F^N.
+fn standard_basis<F: Field, const N: usize>() -> Vec<Tuple<F, N>> {
+    (0..N).map(|i| {
+        let mut e = Tuple::<F, N>::zero();
+        e[i] = F::one();
+        e
+    }).collect()
+}
+
+fn to_row<F: Field, const N: usize>(v: &Tuple<F, N>) -> Vec<F> {
+    (0..N).map(|i| v[i]).collect()
+}
+
+fn to_tuple<F: Field, const N: usize>(row: &[F]) -> Tuple<F, N> {
+    let mut v = Tuple::<F, N>::zero();
+    for i in 0..N {
+        v[i] = row[i];
+    }
+    v
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Subspace;
+    use crate::field::{IntMod2, Rational};
+    use crate::tuple;
+
+    #[test]
+    fn subspace_span() {
+        let w = Subspace::span(&[tuple![1, 2, 1], tuple![2, 4, 2], tuple![0, 1, 1]]);
+        assert_eq!(w.dim(), 2);
+        assert!(w.contains(&tuple![1, 3, 2]));
+        assert!(!w.contains(&tuple![0, 0, 1]));
+        assert_eq!(w, Subspace::span(&[tuple![1, 3, 2], tuple![1, 1, 0]]));
+    }
+
+    #[test]
+    fn subspace_sum_and_intersection() {
+        let w1 = Subspace::span(&[tuple![1, 0, 0, 0], tuple![0, 1, 0, 0], tuple![0, 0, 1, 0]]);
+        let w2 = Subspace::span(&[tuple![1, 1, 0, 0], tuple![0, 0, 1, 1]]);
+        let sum = w1.sum(&w2);
+        let intersection = w1.intersection(&w2);
+        assert_eq!(sum, Subspace::<Rational, 4>::whole());
+        assert_eq!(intersection, Subspace::span(&[tuple![1, 1, 0, 0]]));
+        assert!(intersection.is_subspace_of(&w1) && intersection.is_subspace_of(&w2));
+        assert_eq!(sum.dim(), w1.dim() + w2.dim() - intersection.dim());
+    }
+
+    #[test]
+    fn subspace_direct_sum() {
+        // F^3 is the direct sum of {(a, b, 0)} and {(0, 0, c)}.
+        let w1 = Subspace::span(&[tuple![1, 0, 0], tuple![0, 1, 0]]);
+        let w2 = Subspace::span(&[tuple![0, 0, 1]]);
+        let whole = Subspace::<Rational, 3>::whole();
+        assert!(whole.is_direct_sum_of(&w1, &w2));
+        assert!(!whole.is_direct_sum_of(&w1, &Subspace::span(&[tuple![1, 1, 1], tuple![0, 0, 1]])));
+
+        let w = Subspace::span(&[tuple![1, 2, 3, 4], tuple![0, 1, 1, 0]]);
+        assert!(Subspace::whole().is_direct_sum_of(&w, &w.complement()));
+    }
+
+    #[test]
+    fn subspace_over_int_mod_2() {
+        let (o, i) = (IntMod2::Zero, IntMod2::One);
+        let w1 = Subspace::span(&[tuple![i, i, o], tuple![o, i, i]]);
+        let w2 = Subspace::span(&[tuple![i, o, i], tuple![o, o, i]]);
+        assert_eq!(w1.dim(), 2);
+        assert_eq!(w1.intersection(&w2), Subspace::span(&[tuple![i, o, i]]));
+        assert_eq!(w1.sum(&w2), Subspace::whole());
+    }
+}
@@ -0,0 +1,286 @@
+use crate::field::Field;
+use crate::linear_map::FnMap;
+use super::{VectorSpace, Entries, FiniteDimensional, Tuple, Matrix};
+use super::echelon::row_reduce;
+use std::ops::{Add, Mul, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssign, Neg};
+use std::marker::PhantomData;
+use std::fmt;
+
+/// A type representing an element of the tensor product V ⊗ W of two finite-dimensional vector spaces.
+/// The standard ordered basis is made up of the simple tensors e_i ⊗ f_j of standard basis vectors,
+/// ordered lexicographically, so that dim(V ⊗ W) = dim(V) dim(W).
+/// The tensor is stored as its coefficients relative to this basis.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TensorProduct<F: Field, V, W>(Vec<F>, PhantomData<(V, W)>);
+
+impl<F: Field, V: FiniteDimensional<F>, W: FiniteDimensional<F>> TensorProduct<F, V, W> {
+    /// Returns the simple tensor v ⊗ w, which is the image of (v, w) under the universal bilinear map.
+    /// Its coefficient on e_i ⊗ f_j is v_i w_j.
+    pub fn simple(v: &V, w: &W) -> Self {
+        let (v, w) = (v.entries(), w.entries());
+        let mut coefficients = Vec::with_capacity(v.len() * w.len());
+        for a in v.iter() {
+            for b in w.iter() {
+                coefficients.push(*a * *b);
+            }
+        }
+        Self(coefficients, PhantomData)
+    }
+
+    /// Returns the coefficient of the tensor on e_i ⊗ f_j.
+    pub fn coefficient(&self, i: usize, j: usize) -> F {
+        self.0[i * W::dim() + j]
+    }
+
+    /// Returns the rank of the tensor, which is the least number of simple tensors summing to it.
+    /// This is the rank of the dim(V) × dim(W) matrix of coefficients.
+    pub fn rank(&self) -> usize {
+        let mut rows: Vec<Vec<F>> = self.0.chunks(W::dim().max(1)).map(|row| row.to_vec()).collect();
+        row_reduce(&mut rows).len()
+    }
+
+    /// Returns whether the tensor is simple, meaning that it equals v ⊗ w for some v and w.
+    pub fn is_simple(&self) -> bool {
+        self.rank() <= 1
+    }
+
+    /// Returns the unique linear map T: V ⊗ W -> U with T(v ⊗ w) = B(v, w), given a bilinear map B
+    /// which the caller is trusted to have made bilinear. This is the universal property of V ⊗ W.
+    pub fn lift<U: VectorSpace<F> + Clone>(bilinear: impl Fn(&V, &W) -> U) -> FnMap<F, Self, U> {
+        let mut images = Vec::new();
+        for v in V::standard_basis().iter() {
+            for w in W::standard_basis().iter() {
+                images.push(bilinear(v, w));
+            }
+        }
+        FnMap::new(move |t: &Self| {
+            let mut out = U::zero();
+            for (image, c) in images.iter().zip(t.0.iter()) {
+                out += image.clone() * *c;
+            }
+            out
+        })
+    }
+}
+
+impl<F: Field, const N: usize> Tuple<F, N> {
+    /// Returns the Kronecker product of two tuples, which lists the entries x_i y_j lexicographically.
+    /// These are the coordinates of x ⊗ y, so the output length `Q` must equal `N * P`.
+    pub fn kronecker<const P: usize, const Q: usize>(&self, other: &Tuple<F, P>) -> Tuple<F, Q> {
+        assert_eq!(Q, N * P, "the Kronecker product of tuples of lengths {} and {} has length {}", N, P, N * P);
+        let mut out = <Tuple<F, Q> as VectorSpace<F>>::zero();
+        for i in 0..N {
+            for j in 0..P {
+                out[i * P + j] = self[i] * other[j];
+            }
+        }
+        out
+    }
+}
+
+impl<F: Field, const M: usize, const N: usize> Matrix<F, M, N> {
+    /// Returns the Kronecker product A ⊗ B, the block matrix whose (i, j) block is A_ij B.
+    /// This is the standard matrix of the tensor product of L_A and L_B,
+    /// so the output must have `R = M * P` rows and `S = N * Q` columns.
+    pub fn kronecker<const P: usize, const Q: usize, const R: usize, const S: usize>(&self, other: &Matrix<F, P, Q>) -> Matrix<F, R, S> {
+        assert_eq!(R, M * P, "the Kronecker product has {} rows", M * P);
+        assert_eq!(S, N * Q, "the Kronecker product has {} columns", N * Q);
+        let mut out = <Matrix<F, R, S> as VectorSpace<F>>::zero();
+        for i in 0..M {
+            for j in 0..N {
+                for k in 0..P {
+                    for l in 0..Q {
+                        out[(i * P + k, j * Q + l)] = self[(i, j)] * other[(k, l)];
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+// Operator implementations for tensors with tensors.
+impl<F: Field, V, W> Neg for TensorProduct<F, V, W> {
+    type Output = Self;
+    fn neg(mut self) -> Self {
+        for c in self.0.iter_mut() {
+            *c = -*c;
+        }
+        self
+    }
+}
+impl<F: Field, V, W> Add<&Self> for TensorProduct<F, V, W> {
+    type Output = Self;
+    fn add(mut self, rhs: &Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl<F: Field, V, W> Add<Self> for TensorProduct<F, V, W> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl<F: Field, V, W> Sub<&Self> for TensorProduct<F, V, W> {
+    type Output = Self;
+    fn sub(mut self, rhs: &Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl<F: Field, V, W> Sub<Self> for TensorProduct<F, V, W> {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+impl<F: Field, V, W> AddAssign<&Self> for TensorProduct<F, V, W> {
+    fn add_assign(&mut self, rhs: &Self) {
+        for i in 0..self.0.len() {
+            self.0[i] += rhs.0[i];
+        }
+    }
+}
+impl<F: Field, V, W> AddAssign<Self> for TensorProduct<F, V, W> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+impl<F: Field, V, W> SubAssign<&Self> for TensorProduct<F, V, W> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        for i in 0..self.0.len() {
+            self.0[i] -= rhs.0[i];
+        }
+    }
+}
+impl<F: Field, V, W> SubAssign<Self> for TensorProduct<F, V, W> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+// Operator implementations for tensors with scalars.
+impl<T: Into<F>, F: Field, V, W> Mul<T> for TensorProduct<F, V, W> {
+    type Output = Self;
+    fn mul(mut self, rhs: T) -> Self {
+        self *= rhs;
+        self
+    }
+}
+impl<T: Into<F>, F: Field, V, W> Div<T> for TensorProduct<F, V, W> {
+    type Output = Self;
+    fn div(mut self, rhs: T) -> Self {
+        self /= rhs;
+        self
+    }
+}
+impl<T: Into<F>, F: Field, V, W> MulAssign<T> for TensorProduct<F, V, W> {
+    fn mul_assign(&mut self, rhs: T) {
+        let scale = rhs.into();
+        for c in self.0.iter_mut() {
+            *c *= scale;
+        }
+    }
+}
+impl<T: Into<F>, F: Field, V, W> DivAssign<T> for TensorProduct<F, V, W> {
+    fn div_assign(&mut self, rhs: T) {
+        let scale = rhs.into();
+        for c in self.0.iter_mut() {
+            *c /= scale;
+        }
+    }
+}
+
+impl<F: Field, V, W> fmt::Display for TensorProduct<F, V, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, c) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", c)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl<F: Field, V, W> Entries<F> for TensorProduct<F, V, W> {
+    fn entries(&self) -> Vec<F> {
+        self.0.clone()
+    }
+}
+
+impl<F: Field, V: FiniteDimensional<F>, W: FiniteDimensional<F>> VectorSpace<F> for TensorProduct<F, V, W> {
+    fn zero() -> Self {
+        Self(vec![F::zero(); V::dim() * W::dim()], PhantomData)
+    }
+}
+
+impl<F: Field, V: FiniteDimensional<F>, W: FiniteDimensional<F>> FiniteDimensional<F> for TensorProduct<F, V, W> {
+    fn dim() -> usize {
+        V::dim() * W::dim()
+    }
+
+    fn from_entries(entries: &[F]) -> Self {
+        Self(entries.to_vec(), PhantomData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TensorProduct;
+    use crate::field::{Complex, Rational};
+    use crate::linear_map::LinearMap;
+    use crate::vector_space::{Entries, FiniteDimensional, Matrix, Tuple};
+    use crate::{tuple, matrix, cmplx};
+
+    type Qubit = Tuple<Complex, 2>;
+
+    #[test]
+    fn tensor_product_simple() {
+        let v = tuple![1, 2];
+        let w = tuple![3, 0, -1];
+        let t = TensorProduct::simple(&v, &w);
+        assert_eq!(<TensorProduct<Rational, Tuple<Rational, 2>, Tuple<Rational, 3>> as FiniteDimensional<Rational>>::dim(), 6);
+        assert_eq!(t.coefficient(1, 0), Rational::from(6));
+        let kronecker: Tuple<Rational, 6> = v.kronecker(&w);
+        assert_eq!(t, TensorProduct::from_entries(&kronecker.entries()));
+        assert_eq!(TensorProduct::simple(&(v.clone() * 2), &w), TensorProduct::simple(&v, &(w.clone() * 2)));
+        assert!(t.is_simple());
+    }
+
+    #[test]
+    fn tensor_product_entanglement() {
+        let zero: Qubit = tuple![cmplx!(1), cmplx!(0)];
+        let one: Qubit = tuple![cmplx!(0), cmplx!(1)];
+        let product = TensorProduct::simple(&zero, &(zero.clone() + &one));
+        assert_eq!(product.rank(), 1);
+        let bell = TensorProduct::simple(&zero, &zero) + TensorProduct::simple(&one, &one);
+        assert_eq!(bell.rank(), 2);
+        assert!(!bell.is_simple());
+    }
+
+    #[test]
+    fn tensor_product_lift() {
+        // The bilinear map B(v, w) = vᵗw lifts to the trace of the coefficient matrix.
+        let t = TensorProduct::<Rational, Tuple<Rational, 2>, Tuple<Rational, 2>>::lift(|v, w| tuple![v[0] * w[0] + v[1] * w[1]]);
+        let x = TensorProduct::simple(&tuple![1, 2], &tuple![3, 4]) + TensorProduct::simple(&tuple![1, 0], &tuple![0, 1]);
+        assert_eq!(t.apply(&x), tuple![11]);
+    }
+
+    #[test]
+    fn tensor_product_kronecker() {
+        let a = matrix![[1, 2], [3, 4]];
+        let b = matrix![[0, 1], [1, 0]];
+        let k: Matrix<Rational, 4, 4> = a.kronecker(&b);
+        assert_eq!(k, matrix![[0, 1, 0, 2], [1, 0, 2, 0], [0, 3, 0, 4], [3, 0, 4, 0]]);
+        let x = tuple![1, -1];
+        let y = tuple![2, 5];
+        let kx: Tuple<Rational, 4> = x.kronecker(&y);
+        let expected: Tuple<Rational, 4> = a.apply(&x).kronecker(&b.apply(&y));
+        assert_eq!(k.apply(&kx), expected);
+    }
+}
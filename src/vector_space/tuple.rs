@@ -1,7 +1,8 @@
-use crate::field::{Field, Real, Complex};
+use crate::field::{Field, Real, Complex, IntMod2};
 use crate::field::rational::{Int, Rational};
-use super::VectorSpace;
+use super::{VectorSpace, Entries, FiniteDimensional};
 use std::ops::{Add, Mul, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssign, Index, IndexMut, Neg};
+use std::iter::FromIterator;
 use std::fmt;
 
 /// A type representing a fixed-size column vector of elements from a field.
@@ -38,6 +39,26 @@ impl<F: Field, const N: usize> Tuple<F, N> {
         }
         Tuple::<G,N>(new_arr)
     }
+
+    /// Returns an iterator over the entries.
+    pub fn iter(&self) -> std::slice::Iter<'_, F> {
+        self.0.iter()
+    }
+
+    /// Returns an iterator over mutable references to the entries.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, F> {
+        self.0.iter_mut()
+    }
+
+    /// Returns the tuple whose entries are f(x_i).
+    pub fn map<G: Field>(&self, f: impl FnMut(F) -> G) -> Tuple<G, N> {
+        self.iter().copied().map(f).collect()
+    }
+
+    /// Returns the tuple whose entries are f(x_i, y_i).
+    pub fn zip_with<G: Field, H: Field>(&self, other: &Tuple<G, N>, mut f: impl FnMut(F, G) -> H) -> Tuple<H, N> {
+        self.iter().zip(other.iter()).map(|(&x, &y)| f(x, y)).collect()
+    }
 }
 
 // From array
@@ -61,6 +82,44 @@ impl<const N: usize> From<[Int;N]> for Tuple<Rational,N> {
     }
 }
 
+// Collecting exactly N entries into a tuple. Panics if the iterator yields any other number of entries.
+impl<F: Field, const N: usize> FromIterator<F> for Tuple<F, N> {
+    fn from_iter<I: IntoIterator<Item=F>>(iter: I) -> Self {
+        let mut arr = [F::zero(); N];
+        let mut count = 0;
+        for x in iter {
+            assert!(count < N, "too many entries to collect into a tuple of length {}", N);
+            arr[count] = x;
+            count += 1;
+        }
+        assert_eq!(count, N, "too few entries to collect into a tuple of length {}", N);
+        Self(arr)
+    }
+}
+
+// Iterating over entries
+impl<F: Field, const N: usize> IntoIterator for Tuple<F, N> {
+    type Item = F;
+    type IntoIter = std::array::IntoIter<F, N>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.0)
+    }
+}
+impl<'a, F: Field, const N: usize> IntoIterator for &'a Tuple<F, N> {
+    type Item = &'a F;
+    type IntoIter = std::slice::Iter<'a, F>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+impl<'a, F: Field, const N: usize> IntoIterator for &'a mut Tuple<F, N> {
+    type Item = &'a mut F;
+    type IntoIter = std::slice::IterMut<'a, F>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
 // Tuple indexing
 impl<F: Field, const N: usize> Index<usize> for Tuple<F,N> {
     type Output = F;
@@ -142,49 +201,71 @@ impl<F: Field, const N: usize> SubAssign<Self> for Tuple<F, N> {
 }
 
 // Operator implementations for tuples with scalars.
-impl<T: Into<F>, F: Field, const N: usize> Mul<T> for Tuple<F, N> {
+impl<F: Field, const N: usize> Mul<F> for Tuple<F, N> {
     type Output = Self;
-    fn mul(mut self, rhs: T) -> Self {
-        let scale = rhs.into();
+    fn mul(mut self, scale: F) -> Self {
         for i in 0..N {
             self[i] *= scale;
         }
         self
     }
 }
-impl<T: Into<F>, F: Field, const N: usize> Div<T> for Tuple<F, N> {
+impl<F: Field, const N: usize> Div<F> for Tuple<F, N> {
     type Output = Self;
-    fn div(mut self, rhs: T) -> Self {
-        let scale = rhs.into();
+    fn div(mut self, scale: F) -> Self {
         for i in 0..N {
             self[i] /= scale;
         }
         self
     }
 }
-impl<T: Into<F>, F: Field, const N: usize> MulAssign<T> for Tuple<F, N> {
-    fn mul_assign(&mut self, rhs: T) {
-        let scale = rhs.into();
+impl<F: Field, const N: usize> MulAssign<F> for Tuple<F, N> {
+    fn mul_assign(&mut self, scale: F) {
         for i in 0..N {
             self[i] *= scale;
         }
     }
 }
-impl<T: Into<F>, F: Field, const N: usize> DivAssign<T> for Tuple<F, N> {
-    fn div_assign(&mut self, rhs: T) {
-        let scale = rhs.into();
+impl<F: Field, const N: usize> DivAssign<F> for Tuple<F, N> {
+    fn div_assign(&mut self, scale: F) {
         for i in 0..N {
             self[i] /= scale;
         }
     }
 }
 
+impl_scalar_conversions! {
+    Tuple[N];
+    Rational: Int, IntMod2;
+    Real: Rational, IntMod2;
+    Complex: Real, Rational, IntMod2;
+    IntMod2: Int;
+}
+
+impl<F: Field, const N: usize> Entries<F> for Tuple<F, N> {
+    fn entries(&self) -> Vec<F> {
+        self.0.to_vec()
+    }
+}
+
 impl<F: Field, const N: usize> VectorSpace<F> for Tuple<F, N> {
     fn zero() -> Self {
         Self([F::zero(); N])
     }
 }
 
+impl<F: Field, const N: usize> FiniteDimensional<F> for Tuple<F, N> {
+    fn dim() -> usize {
+        N
+    }
+
+    fn from_entries(entries: &[F]) -> Self {
+        let mut arr = [F::zero(); N];
+        arr.copy_from_slice(entries);
+        Self(arr)
+    }
+}
+
 // Tuples of complex numbers are a vector space over the real numbers. (1.2 Ex 14)
 impl<const N: usize> VectorSpace<Real> for Tuple<Complex, N> {
     fn zero() -> Self {
@@ -218,6 +299,7 @@ impl<F: Field, const N: usize> fmt::Display for Tuple<F, N> {
 #[cfg(test)]
 mod tests {
     use crate::cmplx;
+    use crate::field::Rational;
     use super::Tuple;
 
     #[test]
@@ -245,4 +327,19 @@ mod tests {
         assert!(tuple![5, -6, 7].is_parallel(&tuple![-5, 6, -7]));
         assert!(!tuple![2, 0, -5].is_parallel(&tuple![5, 0, -2]));
     }
+
+    #[test]
+    fn tuple_iteration() {
+        let mut x = tuple![1, 2, 3];
+        for entry in x.iter_mut() {
+            *entry *= Rational::from(2);
+        }
+        assert_eq!(x, tuple![2, 4, 6]);
+        assert_eq!(x.iter().count(), 3);
+        assert_eq!(x.map(|a| a - Rational::from(1)), tuple![1, 3, 5]);
+        assert_eq!(x.zip_with(&tuple![1, 1, 1], |a, b| a + b), tuple![3, 5, 7]);
+        let collected: Tuple<f64, 2> = vec![0.5, 1.5].into_iter().collect();
+        assert_eq!(collected, tuple![0.5, 1.5]);
+        assert_eq!(collected.into_iter().sum::<f64>(), 2.0);
+    }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use super::VectorSpace;
+use super::{VectorSpace, Entries, FiniteDimensional};
 use std::ops::{Add, Mul, Sub, Div, AddAssign, MulAssign, SubAssign, DivAssign, Neg};
 use crate::field::Field;
 
@@ -74,6 +74,22 @@ impl<F: Field> VectorSpace<F> for Zero {
     }
 }
 
+impl<F: Field> Entries<F> for Zero {
+    fn entries(&self) -> Vec<F> {
+        vec![]
+    }
+}
+
+impl<F: Field> FiniteDimensional<F> for Zero {
+    fn dim() -> usize {
+        0
+    }
+
+    fn from_entries(_: &[F]) -> Self {
+        Zero()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Zero;